@@ -0,0 +1,522 @@
+use std::{
+    env, panic,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use lib::{
+    answers::{Answers, ANSWERS_FILE},
+    bench::{bench_day, percent_change, Report},
+    cli::{exit_with_error, Part},
+    client::{fetch_input, ClientConfig, Fetched},
+    days,
+    gen::generate,
+    io_utils::try_read_input_for_day,
+    profiles::{default_profiles_dir, run_matrix},
+    runner::{self, format_duration, PartRun, Selection, Status},
+    scaffold::new_day,
+    submit::{submit_answer, Submission},
+    watch::{diff_answers, run_day_binary, watched_dirs, RunAnswers, Snapshot},
+};
+
+const USAGE: &str = "Usage: aoc <command>
+
+Commands:
+  run [DAYS...] [--all] [--part 1|2] [--parallel] [--jobs N]
+                                 run days (e.g. `4` or `1..10`) and check the answers,
+                                 optionally on N threads
+  bench [DAYS...] [--all] [--iterations N] [--baseline PATH] [--threshold PCT] [--save]
+        [--generated SIZE [--seed N]]
+                                 time parsing and both parts, comparing with a saved
+                                 baseline; --save replaces the baseline; --generated
+                                 runs on a generated input instead of the real one
+  matrix [DAYS...] [--all] [--part 1|2]
+                                 run days on every profile in inputs/dayN/<profile>.in
+                                 and show which pass
+  watch <day>                    rerun a day on its input and examples whenever its
+                                 sources or src/lib change, showing changed answers
+  gen <day> [--seed N] [--size N]
+                                 print a random input for the day
+  new <day>                      create the files of a new day from a template
+  fetch <day>                    download the day's input into src/bin/dayN/input.in
+  submit <day> <part> <answer>   post an answer, unless it is known to be wrong";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["run", args @ ..] => run(args),
+        ["bench", args @ ..] => bench(args),
+        ["matrix", args @ ..] => matrix(parse_selection(args)),
+        ["watch", day] => watch(parse_day(day)),
+        ["gen", day, args @ ..] => gen(parse_day(day), args),
+        ["new", day] => new(parse_day(day)),
+        ["fetch", day] => fetch(parse_day(day)),
+        ["submit", day, part, answer] => submit(parse_day(day), parse_part(part), answer),
+        ["-h" | "--help"] => println!("{}", USAGE),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
+
+fn parse_day(day: &str) -> u32 {
+    day.parse()
+        .unwrap_or_else(|_| exit_with_error(format!("invalid day {:?}", day)))
+}
+
+fn parse_part(part: &str) -> Part {
+    part.parse()
+        .ok()
+        .and_then(Part::from_number)
+        .unwrap_or_else(|| exit_with_error(format!("invalid part {:?}, expected 1 or 2", part)))
+}
+
+fn parse_selection(args: &[&str]) -> Selection {
+    let mut selection = Selection {
+        days: vec![],
+        part: None,
+    };
+    let mut all = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--all" => all = true,
+            "--part" => match args.next() {
+                Some(part) => selection.part = Some(parse_part(part)),
+                None => exit_with_error("--part requires a value (1 or 2)"),
+            },
+            flag if flag.starts_with("--part=") => {
+                selection.part = Some(parse_part(&flag["--part=".len()..]))
+            }
+            flag if flag.starts_with('-') => exit_with_error(format!("unknown flag {}", flag)),
+            days => selection
+                .add(days)
+                .unwrap_or_else(|err| exit_with_error(err)),
+        }
+    }
+
+    if all {
+        selection.days = Selection::all().days;
+    } else if selection.days.is_empty() {
+        exit_with_error("no days selected, pass days like `1..10` or --all");
+    }
+    selection
+}
+
+fn run(args: &[&str]) {
+    let mut jobs = None;
+    let mut selection_args = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--parallel" => {
+                jobs = Some(thread::available_parallelism().map_or(1, |jobs| jobs.get()))
+            }
+            "--jobs" => {
+                jobs = args
+                    .next()
+                    .and_then(|jobs| jobs.parse().ok())
+                    .filter(|jobs| *jobs > 0)
+                    .map(Some)
+                    .unwrap_or_else(|| exit_with_error("--jobs requires a positive number"))
+            }
+            _ => selection_args.push(*arg),
+        }
+    }
+    let selection = parse_selection(&selection_args);
+
+    let answers = Answers::load().unwrap_or_else(|err| {
+        eprintln!("warning: {}, answers won't be checked", err);
+        Answers::default()
+    });
+
+    println!(
+        "{:>3}  {:<8}  {:>10}  {:<6}  Answer",
+        "Day", "Part", "Time", "Status"
+    );
+    let start = Instant::now();
+    let runs = match jobs {
+        Some(jobs) => {
+            // panics are reported in the summary, don't interleave them
+            let runs = without_panic_output(|| runner::run_parallel(&selection, &answers, jobs));
+
+            runs.iter().for_each(print_row);
+            runs
+        }
+        None => {
+            let mut runs = vec![];
+            for day in &selection.days {
+                for run in runner::run_day(*day, &selection, &answers) {
+                    print_row(&run);
+                    runs.push(run);
+                }
+            }
+            runs
+        }
+    };
+
+    if !print_summary(&runs, start.elapsed()) {
+        process::exit(1);
+    }
+}
+
+/// Runs `f` without printing panics, for when they are caught and reported
+/// later.
+fn without_panic_output<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+/// Prints how many parts passed and why the others didn't. Returns whether
+/// all of them passed.
+fn print_summary(runs: &[PartRun], wall_time: Duration) -> bool {
+    let count = |status| runs.iter().filter(|run| run.status() == status).count();
+    println!(
+        "\n{} ok, {} wrong, {} failed, {} unchecked in {}",
+        count(Status::Correct),
+        count(Status::Wrong),
+        count(Status::Failed),
+        count(Status::Unchecked),
+        format_duration(wall_time)
+    );
+
+    let mut passed = true;
+    for run in runs {
+        match (run.status(), &run.outcome) {
+            (Status::Failed, Err(message)) => {
+                eprintln!("Day {} {} failed: {}", run.day, run.part, message)
+            }
+            (Status::Wrong, _) => eprintln!(
+                "Day {} {} doesn't match the answer in {}",
+                run.day, run.part, ANSWERS_FILE
+            ),
+            _ => continue,
+        }
+        passed = false;
+    }
+    passed
+}
+
+fn print_row(run: &PartRun) {
+    let answer = match &run.outcome {
+        Ok(answer) => answer.to_string(),
+        Err(message) => message.clone(),
+    };
+    let mut lines = answer.lines();
+
+    println!(
+        "{:>3}  {:<8}  {:>10}  {:<6}  {}",
+        run.day,
+        run.part,
+        format_duration(run.elapsed),
+        run.status(),
+        lines.next().unwrap_or_default()
+    );
+    // multi-line answers (e.g. letters drawn on a screen) continue below
+    for line in lines {
+        println!("{:>35}{}", "", line);
+    }
+    if run.status() == Status::Wrong {
+        println!(
+            "{:>35}expected {}",
+            "",
+            run.expected.as_deref().unwrap_or_default()
+        );
+    }
+}
+
+fn bench(args: &[&str]) {
+    let mut iterations = 100;
+    let mut baseline_path = Report::default_baseline_path();
+    let mut threshold = 20.0;
+    let mut save = false;
+    let mut generated = None;
+    let mut seed = 0;
+    let mut selection_args = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| {
+            args.next()
+                .copied()
+                .unwrap_or_else(|| exit_with_error(format!("{} requires a value", flag)))
+        };
+        match *arg {
+            "--iterations" => {
+                iterations = value(arg)
+                    .parse()
+                    .ok()
+                    .filter(|iterations| *iterations > 0)
+                    .unwrap_or_else(|| exit_with_error("--iterations should be a positive number"))
+            }
+            "--baseline" => baseline_path = PathBuf::from(value(arg)),
+            "--threshold" => {
+                threshold = value(arg)
+                    .parse()
+                    .unwrap_or_else(|_| exit_with_error("--threshold should be a percentage"))
+            }
+            "--save" => save = true,
+            "--generated" => {
+                generated = Some(value(arg).parse().unwrap_or_else(|_| {
+                    exit_with_error("--generated should be the size of the input")
+                }))
+            }
+            "--seed" => {
+                seed = value(arg)
+                    .parse()
+                    .unwrap_or_else(|_| exit_with_error("--seed should be a number"))
+            }
+            _ => selection_args.push(*arg),
+        }
+    }
+    let selection = parse_selection(&selection_args);
+    let parts: Vec<Part> = selection.parts().collect();
+
+    let baseline = if save || !baseline_path.exists() {
+        None
+    } else {
+        Some(Report::load(&baseline_path).unwrap_or_else(|err| exit_with_error(err)))
+    };
+
+    let mut report = Report {
+        iterations,
+        ..Report::default()
+    };
+    for &day in &selection.days {
+        let solution = days::get(day).unwrap();
+        let input = match generated {
+            Some(size) => generate(day, seed, size).unwrap_or_else(|| {
+                exit_with_error(format!("there is no generator for day {}", day))
+            }),
+            None => try_read_input_for_day(day).unwrap_or_else(|err| exit_with_error(err)),
+        };
+        for (phase, stats) in bench_day(solution, &input, &parts, iterations) {
+            report.results.insert((day, phase), stats);
+        }
+    }
+
+    println!(
+        "{:>3}  {:<8}  {:>10}  {:>10}  {:>10}  Baseline",
+        "Day", "Phase", "Min", "Median", "p95"
+    );
+    for (&(day, phase), stats) in &report.results {
+        let change = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.results.get(&(day, phase)))
+            .and_then(|before| percent_change(before.median, stats.median))
+            .map(|change| format!("{:+.1}%", change))
+            .unwrap_or_default();
+        println!(
+            "{:>3}  {:<8}  {:>10}  {:>10}  {:>10}  {}",
+            day,
+            phase,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.p95),
+            change
+        );
+    }
+
+    if save {
+        report
+            .save(&baseline_path)
+            .unwrap_or_else(|err| exit_with_error(err));
+        println!("\nSaved baseline to {}", baseline_path.display());
+        return;
+    }
+
+    let Some(baseline) = baseline else {
+        println!(
+            "\nNo baseline at {}, run with --save to create one",
+            baseline_path.display()
+        );
+        return;
+    };
+    let regressions = report.regressions(&baseline, threshold);
+    if !regressions.is_empty() {
+        eprintln!();
+        for regression in &regressions {
+            eprintln!(
+                "Day {} {}: median {} -> {} ({:+.1}%)",
+                regression.day,
+                regression.phase,
+                format_duration(regression.baseline),
+                format_duration(regression.current),
+                regression.change
+            );
+        }
+        eprintln!(
+            "{} phase(s) regressed by more than {}%",
+            regressions.len(),
+            threshold
+        );
+        process::exit(1);
+    }
+}
+
+fn matrix(selection: Selection) {
+    let dir = default_profiles_dir();
+    let matrix = without_panic_output(|| run_matrix(&dir, &selection))
+        .unwrap_or_else(|err| exit_with_error(err));
+    if matrix.profiles.is_empty() {
+        println!("No profiles in {}", dir.display());
+        return;
+    }
+
+    let width = matrix
+        .profiles
+        .iter()
+        .map(|profile| profile.len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    print!("Day");
+    for profile in &matrix.profiles {
+        print!("  {:<width$}", profile, width = width);
+    }
+    println!();
+    for &day in &matrix.days {
+        print!("{:>3}", day);
+        for profile in &matrix.profiles {
+            let status = matrix
+                .status(day, profile)
+                .map_or("-".to_string(), |status| status.to_string());
+            print!("  {:<width$}", status, width = width);
+        }
+        println!();
+    }
+
+    let mut failures = matrix
+        .cells
+        .iter()
+        .flat_map(|((_, profile), runs)| runs.iter().map(move |run| (profile, run)))
+        .filter(|(_, run)| matches!(run.status(), Status::Wrong | Status::Failed))
+        .peekable();
+    if failures.peek().is_none() {
+        return;
+    }
+    eprintln!();
+    for (profile, run) in failures {
+        match &run.outcome {
+            Ok(answer) => eprintln!(
+                "Day {} {} of {}: expected {}, got {}",
+                run.day,
+                run.part,
+                profile,
+                run.expected.as_deref().unwrap_or_default(),
+                answer
+            ),
+            Err(message) => {
+                eprintln!("Day {} {} of {}: {}", run.day, run.part, profile, message)
+            }
+        }
+    }
+    process::exit(1);
+}
+
+fn watch(day: u32) {
+    if days::get(day).is_none() {
+        exit_with_error(format!("day {} is not solved yet", day));
+    }
+    let dirs = watched_dirs(day);
+    let dirs_list: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+    println!("Watching {}", dirs_list.join(", "));
+
+    let mut seen = Snapshot::default();
+    let mut previous: Option<RunAnswers> = None;
+    loop {
+        let snapshot = Snapshot::take(&dirs).unwrap_or_else(|err| exit_with_error(err));
+        let changed = snapshot.changed_since(&seen);
+
+        if !changed.is_empty() {
+            if previous.is_some() {
+                for path in changed {
+                    println!("\nChanged {}", path.display());
+                }
+            }
+
+            let answers = run_day_binary(day);
+            let changes = diff_answers(previous.as_ref().unwrap_or(&RunAnswers::new()), &answers);
+            if changes.is_empty() {
+                println!("No answers changed");
+            }
+            for change in changes {
+                println!("{}", change);
+            }
+
+            previous = Some(answers);
+            seen = snapshot;
+        }
+
+        thread::sleep(Duration::from_millis(500));
+    }
+}
+
+fn gen(day: u32, args: &[&str]) {
+    let mut seed = 0;
+    let mut size = 100;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(|| exit_with_error(format!("{} requires a number", arg)));
+        match *arg {
+            "--seed" => seed = value,
+            "--size" => size = value as usize,
+            flag => exit_with_error(format!("unknown flag {}", flag)),
+        }
+    }
+
+    match generate(day, seed, size) {
+        Some(input) => println!("{}", input),
+        None => exit_with_error(format!("there is no generator for day {}", day)),
+    }
+}
+
+fn new(day: u32) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match new_day(day, root) {
+        Ok(files) => {
+            println!("Created day {}:", day);
+            for file in files {
+                let file = file.strip_prefix(root).unwrap_or(&file);
+                println!("  {}", file.display());
+            }
+        }
+        Err(err) => exit_with_error(err),
+    }
+}
+
+fn fetch(day: u32) {
+    match fetch_input(day, &ClientConfig::from_env()) {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded input for day {} to {}", day, path.display())
+        }
+        Ok(Fetched::Cached(path)) => {
+            println!("Input for day {} is already at {}", day, path.display())
+        }
+        Err(err) => exit_with_error(err),
+    }
+}
+
+fn submit(day: u32, part: Part, answer: &str) {
+    match submit_answer(day, part, answer, &ClientConfig::from_env()) {
+        Ok(Submission::Posted(verdict)) => println!("Day {} {}: {}", day, part, verdict),
+        Ok(Submission::Known(verdict)) => println!(
+            "Day {} {}: not submitted, {} is already known to be {}",
+            day, part, answer, verdict
+        ),
+        Err(err) => exit_with_error(err),
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{cli::Part, io_utils::INPUT_DIR_ENV};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers for the puzzle inputs, keyed by day and part:
+///
+/// ```toml
+/// [day1]
+/// part_one = 74394
+/// part_two = 212836
+/// ```
+///
+/// Answers may be written as integers or strings; they are compared as the
+/// displayed text of a solution's result.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u32, BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    /// Where the answers for the current inputs live: next to the inputs if
+    /// [`INPUT_DIR_ENV`] is set, otherwise in the crate's root.
+    pub fn default_path() -> PathBuf {
+        match env::var_os(INPUT_DIR_ENV) {
+            Some(dir) => Path::new(&dir).join(ANSWERS_FILE),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE),
+        }
+    }
+
+    pub fn load() -> Result<Self, AnswersError> {
+        Self::load_from_path(Self::default_path())
+    }
+
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self, AnswersError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|source| AnswersError::Io {
+            path: path.into(),
+            source,
+        })?;
+
+        Self::parse(&content).map_err(|message| AnswersError::Invalid {
+            path: path.into(),
+            message,
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table: toml::Table = content.parse().map_err(|err| format!("{}", err))?;
+        let mut answers = Self::default();
+
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("unexpected key `{}`, expected `dayN`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` should be a table", day_key))?;
+
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "part_one" => Part::One,
+                    "part_two" => Part::Two,
+                    _ => return Err(format!("unexpected key `{}.{}`", day_key, part_key)),
+                };
+                let answer = match answer {
+                    toml::Value::String(answer) => answer.to_owned(),
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "`{}.{}` should be a string or an integer",
+                            day_key, part_key
+                        ))
+                    }
+                };
+                answers.insert(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.days.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, answer: String) {
+        self.days.entry(day).or_default().insert(part, answer);
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    Invalid { path: PathBuf, message: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            AnswersError::Invalid { path, message } => {
+                write!(f, "invalid answers file {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Io { source, .. } => Some(source),
+            AnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+/// Test helper comparing a solution's result with the recorded answer.
+///
+/// Panics on a mismatch. Parts without a recorded answer only print a note,
+/// so that partially known answers for someone else's inputs still work.
+pub fn check_answer(day: u32, part: Part, actual: impl Display) {
+    let answers = Answers::load().unwrap_or_else(|err| panic!("{}", err));
+    let actual = actual.to_string();
+
+    match answers.get(day, part) {
+        Some(expected) => assert_eq!(
+            actual, expected,
+            "Day {} {} doesn't match the answer in {}",
+            day, part, ANSWERS_FILE
+        ),
+        None => eprintln!("Day {} {}: no recorded answer, got {}", day, part, actual),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Answers, ANSWERS_FILE};
+    use crate::{cli::Part, io_utils::try_read_input_for_day};
+
+    #[test]
+    fn test_parsing_answers() {
+        let answers = Answers::parse(
+            "[day1]\npart_one = 24000\n\n[day5]\npart_one = \"CMZ\"\npart_two = \"MCD\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("24000"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(5, Part::Two), Some("MCD"));
+        assert_eq!(answers.days().collect::<Vec<_>>(), vec![1, 5]);
+
+        assert!(Answers::parse("[one]\npart_one = 1").is_err());
+        assert!(Answers::parse("[day1]\npart_three = 1").is_err());
+        assert!(Answers::parse("[day1]\npart_one = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_repository_answers_file() {
+        let answers =
+            Answers::load_from_path(Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE))
+                .unwrap();
+        let recorded: Vec<u32> = answers.days().collect();
+        // days scaffolded by `aoc new` have an empty input until fetched
+        for day in (1..=25).filter(|day| try_read_input_for_day(*day).is_ok()) {
+            assert!(
+                recorded.contains(&day),
+                "Day {} has no recorded answers",
+                day
+            );
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{cli::Part, DynSolution};
+
+pub const BASELINE_FILE: &str = "bench-baseline.json";
+
+/// A separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    pub fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part_one",
+            Phase::Part(Part::Two) => "part_two",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "parse" => Some(Phase::Parse),
+            "part_one" => Some(Phase::Part(Part::One)),
+            "part_two" => Some(Phase::Part(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("Parse"),
+            Phase::Part(part) => f.pad(&part.to_string()),
+        }
+    }
+}
+
+/// Wall time statistics over all iterations of a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "Stats need at least one sample");
+        samples.sort();
+
+        let percentile = |p: usize| samples[(samples.len() - 1) * p / 100];
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// Timings of the benchmarked phases, keyed by day and phase.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Report {
+    pub iterations: usize,
+    pub results: BTreeMap<(u32, Phase), Stats>,
+}
+
+impl Report {
+    /// Default location of a saved baseline, in the crate's root.
+    pub fn default_baseline_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(BASELINE_FILE)
+    }
+
+    /// Saves the report as JSON:
+    ///
+    /// ```json
+    /// {"iterations": 100, "days": {"day1": {"parse": {"min_ns": 1, "median_ns": 2, "p95_ns": 3}}}}
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut days = Map::new();
+        for ((day, phase), stats) in &self.results {
+            let day = days
+                .entry(format!("day{}", day))
+                .or_insert_with(|| json!({}));
+            day[phase.key()] = json!({
+                "min_ns": stats.min.as_nanos() as u64,
+                "median_ns": stats.median.as_nanos() as u64,
+                "p95_ns": stats.p95.as_nanos() as u64,
+            });
+        }
+
+        let report = json!({ "iterations": self.iterations, "days": days });
+        serde_json::to_string_pretty(&report).unwrap()
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let report: Value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+        let mut parsed = Self {
+            iterations: report["iterations"].as_u64().unwrap_or_default() as usize,
+            ..Self::default()
+        };
+
+        let days = report["days"]
+            .as_object()
+            .ok_or("`days` should be an object")?;
+        for (day_key, phases) in days {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("unexpected key `{}`, expected `dayN`", day_key))?;
+            let phases = phases
+                .as_object()
+                .ok_or_else(|| format!("`{}` should be an object", day_key))?;
+
+            for (phase_key, stats) in phases {
+                let phase = Phase::from_key(phase_key)
+                    .ok_or_else(|| format!("unexpected key `{}.{}`", day_key, phase_key))?;
+                let nanos = |name: &str| {
+                    stats[name]
+                        .as_u64()
+                        .map(Duration::from_nanos)
+                        .ok_or_else(|| {
+                            format!("`{}.{}.{}` should be a number", day_key, phase_key, name)
+                        })
+                };
+                let stats = Stats {
+                    min: nanos("min_ns")?,
+                    median: nanos("median_ns")?,
+                    p95: nanos("p95_ns")?,
+                };
+                parsed.results.insert((day, phase), stats);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        fs::write(path, self.to_json() + "\n")
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+        Self::from_json(&content)
+            .map_err(|err| format!("invalid baseline {}: {}", path.display(), err))
+    }
+
+    /// Phases whose median got slower than in `baseline` by more than
+    /// `threshold` percent.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        self.results
+            .iter()
+            .filter_map(|(&(day, phase), stats)| {
+                let before = baseline.results.get(&(day, phase))?.median;
+                let change = percent_change(before, stats.median)?;
+                (change > threshold).then_some(Regression {
+                    day,
+                    phase,
+                    baseline: before,
+                    current: stats.median,
+                    change,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u32,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// How much slower the current run is, in percent.
+    pub change: f64,
+}
+
+/// Relative change from `before` to `after` in percent, if `before` is
+/// non-zero.
+pub fn percent_change(before: Duration, after: Duration) -> Option<f64> {
+    if before.is_zero() {
+        return None;
+    }
+    Some((after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0)
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&mut samples)
+}
+
+/// Times parsing and each of the given parts separately, `iterations`
+/// times each. The parts are timed on an input parsed once up front.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Vec<(Phase, Stats)> {
+    let mut results = vec![(Phase::Parse, time(iterations, || solution.parse_any(input)))];
+
+    let parsed = solution.parse_any(input);
+    for &part in parts {
+        let stats = time(iterations, || solution.run_parsed(part, parsed.as_ref()));
+        results.push((Phase::Part(part), stats));
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bench_day, percent_change, Phase, Report, Stats};
+    use crate::{cli::Part, days};
+
+    #[test]
+    fn test_stats() {
+        let mut samples: Vec<Duration> = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+
+        let stats = Stats::from_samples(&mut [Duration::from_millis(7)]);
+        assert_eq!(stats.median, stats.p95);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip_and_regressions() {
+        let stats = |median: u64| Stats {
+            min: Duration::from_micros(median / 2),
+            median: Duration::from_micros(median),
+            p95: Duration::from_micros(median * 2),
+        };
+        let mut baseline = Report {
+            iterations: 10,
+            ..Report::default()
+        };
+        baseline.results.insert((7, Phase::Parse), stats(100));
+        baseline
+            .results
+            .insert((7, Phase::Part(Part::One)), stats(100));
+
+        let parsed = Report::from_json(&baseline.to_json()).unwrap();
+        assert_eq!(parsed, baseline);
+        assert!(Report::from_json("{\"days\": {\"day1\": {\"part_three\": {}}}}").is_err());
+
+        let mut current = baseline.clone();
+        current
+            .results
+            .insert((7, Phase::Part(Part::One)), stats(150));
+        current.results.insert((8, Phase::Parse), stats(1000));
+        let regressions = current.regressions(&baseline, 20.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, Phase::Part(Part::One));
+        assert_eq!(regressions[0].change.round(), 50.0);
+        assert!(current.regressions(&baseline, 60.0).is_empty());
+
+        assert_eq!(percent_change(Duration::ZERO, Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let example = "1000\n2000\n\n4000\n\n5000";
+        let results = bench_day(days::get(1).unwrap(), example, &[Part::Two], 3);
+        let phases: Vec<Phase> = results.iter().map(|(phase, _)| *phase).collect();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Part(Part::Two)]);
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    cell::OnceCell,
+    env,
+    error::Error,
+    fmt::{self, Display},
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+use serde_json::json;
+
+use crate::{
+    io_utils::{
+        chunks_from_stdin, input_checksum, lines_from_stdin, resolve_day_file,
+        try_chunks_from_path, try_lines_for_day, try_lines_from_path, try_read_input_for_day,
+        try_read_input_from_path, try_read_input_from_stdin, InputChunks, InputError, InputLines,
+    },
+    Answer, DynSolution, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// How a binary prints its answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// `Part One: answer` lines for humans.
+    #[default]
+    Text,
+    /// One JSON object per line and part, for scripts.
+    Json,
+}
+
+/// Where a binary should take its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `input.in`.
+    Day(u32),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Day(day) => try_read_input_for_day(*day),
+            InputSource::Path(path) => try_read_input_from_path(path),
+            InputSource::Stdin => try_read_input_from_stdin(),
+        }
+    }
+
+    pub fn lines(&self) -> Result<InputLines, InputError> {
+        match self {
+            InputSource::Day(day) => try_lines_for_day(*day),
+            InputSource::Path(path) => try_lines_from_path(path),
+            InputSource::Stdin => Ok(lines_from_stdin()),
+        }
+    }
+
+    pub fn chunks(&self, chunk_size: usize) -> Result<InputChunks, InputError> {
+        match self {
+            InputSource::Day(day) => {
+                let path = resolve_day_file(*day, "input.in")?;
+                try_chunks_from_path(path, chunk_size)
+            }
+            InputSource::Path(path) => try_chunks_from_path(path, chunk_size),
+            InputSource::Stdin => Ok(chunks_from_stdin(chunk_size)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    HelpRequested,
+    MissingPart,
+    InvalidPart(String),
+    MissingFormat,
+    InvalidFormat(String),
+    UnknownFlag(String),
+    UnexpectedArgument(String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::HelpRequested => write!(f, "help requested"),
+            ArgsError::MissingPart => write!(f, "--part requires a value (1 or 2)"),
+            ArgsError::InvalidPart(part) => write!(f, "invalid part {:?}, expected 1 or 2", part),
+            ArgsError::MissingFormat => write!(f, "--format requires a value (text or json)"),
+            ArgsError::InvalidFormat(format) => {
+                write!(f, "invalid format {:?}, expected text or json", format)
+            }
+            ArgsError::UnknownFlag(flag) => write!(f, "unknown flag {}", flag),
+            ArgsError::UnexpectedArgument(arg) => write!(f, "unexpected argument {}", arg),
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+/// Command-line arguments shared by every `dayN` binary:
+///
+/// ```text
+/// dayN [--part 1|2] [--format text|json] [PATH | -]
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub input: InputSource,
+    pub part: Option<Part>,
+    pub format: Format,
+    stdin: OnceCell<String>,
+}
+
+impl Args {
+    pub fn parse<I>(day: u32, args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut input = None;
+        let mut part = None;
+        let mut format = Format::Text;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::HelpRequested),
+                "--part" => {
+                    let value = args.next().ok_or(ArgsError::MissingPart)?;
+                    part = Some(parse_part(&value)?);
+                }
+                flag if flag.starts_with("--part=") => {
+                    part = Some(parse_part(&flag["--part=".len()..])?);
+                }
+                "--format" => {
+                    let value = args.next().ok_or(ArgsError::MissingFormat)?;
+                    format = parse_format(&value)?;
+                }
+                flag if flag.starts_with("--format=") => {
+                    format = parse_format(&flag["--format=".len()..])?;
+                }
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(ArgsError::UnknownFlag(flag.to_string()))
+                }
+                _ if input.is_some() => return Err(ArgsError::UnexpectedArgument(arg)),
+                "-" => input = Some(InputSource::Stdin),
+                path => input = Some(InputSource::Path(path.into())),
+            }
+        }
+
+        Ok(Self {
+            input: input.unwrap_or(InputSource::Day(day)),
+            part,
+            format,
+            stdin: OnceCell::new(),
+        })
+    }
+
+    /// Parses the process arguments, printing usage and exiting on error.
+    pub fn from_env(day: u32) -> Self {
+        match Self::parse(day, env::args().skip(1)) {
+            Ok(args) => args,
+            Err(ArgsError::HelpRequested) => {
+                println!("{}", usage(day));
+                process::exit(0);
+            }
+            Err(err) => {
+                eprintln!("error: {}\n\n{}", err, usage(day));
+                process::exit(2);
+            }
+        }
+    }
+
+    pub fn runs_part(&self, part: Part) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+
+    /// Reads the selected input, exiting with the error message on failure.
+    pub fn read_input_or_exit(&self) -> String {
+        self.input.read().unwrap_or_else(|err| exit_with_error(err))
+    }
+
+    /// Streams the selected input line by line, exiting on read errors.
+    ///
+    /// Files are reopened on every call. Stdin can only be consumed once, so
+    /// when both parts run from stdin it is buffered on the first call.
+    pub fn lines_or_exit(&self) -> Box<dyn Iterator<Item = String> + '_> {
+        if self.input == InputSource::Stdin && self.part.is_none() {
+            let input = self.stdin.get_or_init(|| self.read_input_or_exit());
+            return Box::new(input.lines().map(String::from));
+        }
+
+        let lines = self
+            .input
+            .lines()
+            .unwrap_or_else(|err| exit_with_error(err));
+        Box::new(lines.map(|line| line.unwrap_or_else(|err| exit_with_error(err))))
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, ArgsError> {
+    value
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| ArgsError::InvalidPart(value.to_string()))
+}
+
+fn parse_format(value: &str) -> Result<Format, ArgsError> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(ArgsError::InvalidFormat(value.to_string())),
+    }
+}
+
+pub fn usage(day: u32) -> String {
+    format!(
+        "Usage: day{} [--part 1|2] [--format text|json] [PATH | -]\n\n\
+         Reads src/bin/day{}/input.in unless PATH is given; `-` reads from stdin.\n\
+         --format json prints one JSON object per part instead of text.",
+        day, day
+    )
+}
+
+/// Entry point of a `dayN` binary: parses the arguments and prints the
+/// answers of the selected parts.
+pub fn run_day<S: Solution>(solution: &S) {
+    let args = Args::from_env(S::DAY);
+
+    if args.format == Format::Json {
+        let input = args.read_input_or_exit();
+        let checksum = input_checksum(&input);
+        for part in Part::ALL.into_iter().filter(|part| args.runs_part(*part)) {
+            let start = Instant::now();
+            let answer = DynSolution::run(solution, part, &input);
+            println!(
+                "{}",
+                answer_json(S::DAY, part, &answer, start.elapsed(), &checksum)
+            );
+        }
+        return;
+    }
+
+    if S::STREAMING {
+        for part in Part::ALL.into_iter().filter(|part| args.runs_part(*part)) {
+            print_answer(part, &solution.stream_part(part, &mut args.lines_or_exit()));
+        }
+        return;
+    }
+
+    let input = solution.parse(&args.read_input_or_exit());
+    if args.runs_part(Part::One) {
+        print_answer(Part::One, &solution.part_one(&input));
+    }
+    if args.runs_part(Part::Two) {
+        print_answer(Part::Two, &solution.part_two(&input));
+    }
+}
+
+/// Prints `Part One: answer`, or the answer below the label if it spans
+/// several lines.
+pub fn print_answer(part: Part, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("{}:\n{}", part, answer);
+    } else {
+        println!("{}: {}", part, answer);
+    }
+}
+
+/// A part's answer as a JSON object with the time it took (including
+/// parsing) and the checksum of the input it was computed from.
+pub fn answer_json(
+    day: u32,
+    part: Part,
+    answer: &Answer,
+    duration: Duration,
+    input_checksum: &str,
+) -> serde_json::Value {
+    json!({
+        "day": day,
+        "part": part.number(),
+        "answer": serde_json::Value::from(answer),
+        "duration_ns": duration.as_nanos() as u64,
+        "input_checksum": input_checksum,
+    })
+}
+
+pub fn exit_with_error(err: impl Display) -> ! {
+    eprintln!("error: {}", err);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{answer_json, Args, ArgsError, Format, InputSource, Part};
+    use crate::Answer;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgsError> {
+        Args::parse(4, args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_defaults_to_day_input() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args.input, InputSource::Day(4));
+        assert!(args.runs_part(Part::One) && args.runs_part(Part::Two));
+    }
+
+    #[test]
+    fn test_path_stdin_and_part() {
+        let args = parse(&["--part", "2", "other.in"]).unwrap();
+        assert_eq!(args.input, InputSource::Path("other.in".into()));
+        assert!(!args.runs_part(Part::One) && args.runs_part(Part::Two));
+
+        let args = parse(&["-", "--part=1"]).unwrap();
+        assert_eq!(args.input, InputSource::Stdin);
+        assert_eq!(args.part, Some(Part::One));
+        assert_eq!(args.format, Format::Text);
+    }
+
+    #[test]
+    fn test_json_format() {
+        let args = parse(&["--format", "json"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(parse(&["--format=json"]).unwrap().format, Format::Json);
+        assert_eq!(parse(&["--format"]), Err(ArgsError::MissingFormat));
+        assert_eq!(
+            parse(&["--format", "xml"]),
+            Err(ArgsError::InvalidFormat("xml".into()))
+        );
+
+        let json = answer_json(
+            5,
+            Part::Two,
+            &Answer::from("MCD"),
+            Duration::from_micros(3),
+            "abc",
+        );
+        assert_eq!(
+            json.to_string(),
+            r#"{"answer":"MCD","day":5,"duration_ns":3000,"input_checksum":"abc","part":2}"#
+        );
+        let json = answer_json(1, Part::One, &Answer::from(24000), Duration::ZERO, "abc");
+        assert_eq!(json["answer"], 24000);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert_eq!(parse(&["--part"]), Err(ArgsError::MissingPart));
+        assert_eq!(
+            parse(&["--part", "3"]),
+            Err(ArgsError::InvalidPart("3".into()))
+        );
+        assert_eq!(
+            parse(&["--verbose"]),
+            Err(ArgsError::UnknownFlag("--verbose".into()))
+        );
+        assert_eq!(
+            parse(&["a.in", "b.in"]),
+            Err(ArgsError::UnexpectedArgument("b.in".into()))
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::io_utils::default_input_dir;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2022;
+
+const USER_AGENT: &str = "github.com/mieszkosabo/advent_of_code_2022";
+
+/// Settings for talking to the Advent of Code website, or to anything that
+/// pretends to be it (e.g. a local stand-in server in tests).
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in account.
+    pub session: Option<String>,
+    pub year: u32,
+    /// Directory laid out like `src/bin`, where inputs are cached.
+    pub input_dir: PathBuf,
+}
+
+impl ClientConfig {
+    /// Reads [`BASE_URL_ENV`] and [`SESSION_ENV`], falling back to the real
+    /// website and no session.
+    pub fn from_env() -> Self {
+        Self {
+            base_url: env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: env::var(SESSION_ENV)
+                .ok()
+                .map(|session| session.trim().to_string()),
+            year: YEAR,
+            input_dir: default_input_dir(),
+        }
+    }
+
+    pub(crate) fn day_url(&self, day: u32, suffix: &str) -> String {
+        format!(
+            "{}/{}/day/{}{}",
+            self.base_url.trim_end_matches('/'),
+            self.year,
+            day,
+            suffix
+        )
+    }
+
+    pub(crate) fn day_dir(&self, day: u32) -> PathBuf {
+        self.input_dir.join(format!("day{}", day))
+    }
+
+    fn request(&self, method: &str, url: &str) -> Result<ureq::Request, ClientError> {
+        let session = self.session.as_deref().ok_or(ClientError::MissingSession)?;
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Ok(agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", session)))
+    }
+
+    pub(crate) fn get(&self, url: &str) -> Result<String, ClientError> {
+        let response = self.request("GET", url)?.call();
+        read_response(url, response)
+    }
+
+    pub(crate) fn post_form(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, ClientError> {
+        let response = self.request("POST", url)?.send_form(form);
+        read_response(url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| ClientError::Transport {
+                url: url.to_string(),
+                message: err.to_string(),
+            }),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status {
+            url: url.to_string(),
+            status,
+        }),
+        Err(ureq::Error::Transport(transport)) => Err(ClientError::Transport {
+            url: url.to_string(),
+            message: transport.to_string(),
+        }),
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status { url: String, status: u16 },
+    Transport { url: String, message: String },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token, set {} to the value of your session cookie",
+                SESSION_ENV
+            ),
+            ClientError::Status { url, status } => {
+                write!(f, "request to {} failed with status {}", url, status)
+            }
+            ClientError::Transport { url, message } => {
+                write!(f, "request to {} failed: {}", url, message)
+            }
+            ClientError::Io { path, source } => {
+                write!(f, "could not access {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+/// Downloads the puzzle input for `day` into `dayN/input.in` of the
+/// configured input directory, unless it is already there. An empty file,
+/// like the one `aoc new` creates, doesn't count as cached.
+pub fn fetch_input(day: u32, config: &ClientConfig) -> Result<Fetched, ClientError> {
+    let day_dir = config.day_dir(day);
+    let path = day_dir.join("input.in");
+    if fs::read(&path).is_ok_and(|cached| !cached.iter().all(u8::is_ascii_whitespace)) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = config.get(&config.day_url(day, "/input"))?;
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| ClientError::Io { path, source }
+    };
+    // renamed into place once complete, so an interrupted write never
+    // leaves a partial input that would count as cached
+    let partial = day_dir.join("input.in.part");
+    fs::create_dir_all(&day_dir).map_err(io_error(&day_dir))?;
+    fs::write(&partial, input).map_err(io_error(&partial))?;
+    fs::rename(&partial, &path).map_err(io_error(&path))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// A tiny HTTP server standing in for the website in tests.
+#[cfg(test)]
+pub(crate) mod mock_server {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// Serves every request with `respond(request) -> (status, body)` and
+    /// records the raw requests. Returns the base url and the recording.
+    pub fn serve<F>(respond: F) -> (String, Arc<Mutex<Vec<String>>>)
+    where
+        F: Fn(&str) -> (u16, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                request.push_str(&String::from_utf8_lossy(&body));
+
+                let (status, body) = respond(&request);
+                recorded.lock().unwrap().push(request);
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{fetch_input, mock_server, ClientConfig, ClientError, Fetched};
+
+    fn config(base_url: String, name: &str) -> ClientConfig {
+        let input_dir: PathBuf =
+            env::temp_dir().join(format!("aoc_client_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+
+        ClientConfig {
+            base_url,
+            session: Some("secret".to_string()),
+            year: 2022,
+            input_dir,
+        }
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let (base_url, requests) = mock_server::serve(|_| (200, "1000\n2000\n".to_string()));
+        let config = config(base_url, "fetch");
+
+        let first = fetch_input(1, &config).unwrap();
+        assert!(matches!(first, Fetched::Downloaded(_)));
+        assert_eq!(fs::read_to_string(first.path()).unwrap(), "1000\n2000\n");
+
+        let second = fetch_input(1, &config).unwrap();
+        assert_eq!(second, Fetched::Cached(first.path().to_owned()));
+        assert!(!config.day_dir(1).join("input.in.part").exists());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1, "Cached input should not be downloaded");
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _) = mock_server::serve(|_| (400, "Please log in".to_string()));
+        let mut config = config(base_url, "fetch_errors");
+
+        assert!(matches!(
+            fetch_input(2, &config),
+            Err(ClientError::Status { status: 400, .. })
+        ));
+        assert!(!config.day_dir(2).exists(), "Nothing should be cached");
+
+        config.session = None;
+        assert!(matches!(
+            fetch_input(2, &config),
+            Err(ClientError::MissingSession)
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+use std::iter;
+
+use crate::{cli::Part, Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const STREAMING: bool = true;
+
+    /// calories carried by each elf
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        calories_per_elf(input.lines()).collect()
+    }
+
+    fn part_one(&self, calories: &Self::Input) -> Answer {
+        max_calories(calories.iter().copied()).into()
+    }
+
+    fn part_two(&self, calories: &Self::Input) -> Answer {
+        top_three_calories(calories.iter().copied()).into()
+    }
+
+    fn stream_part(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        let calories = calories_per_elf(lines);
+        match part {
+            Part::One => max_calories(calories).into(),
+            Part::Two => top_three_calories(calories).into(),
+        }
+    }
+}
+
+/// Sums each elf's calories. Elves are delimited by empty lines.
+fn calories_per_elf<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+) -> impl Iterator<Item = u32> {
+    let mut lines = lines.into_iter().peekable();
+
+    iter::from_fn(move || {
+        lines.peek()?;
+        let calories = lines
+            .by_ref()
+            // stop at (and consume) the empty line delimiting the next elf
+            .take_while(|el| !el.as_ref().is_empty())
+            .map(|el| el.as_ref().parse::<u32>().unwrap())
+            .sum::<u32>();
+
+        Some(calories)
+    })
+}
+
+fn max_calories(calories: impl Iterator<Item = u32>) -> u32 {
+    calories.max().unwrap()
+}
+
+fn top_three_calories(calories: impl Iterator<Item = u32>) -> u32 {
+    // keep only the three highest values, sorted ascending
+    let mut top_three = [0; 3];
+
+    for calories in calories {
+        if calories > top_three[0] {
+            top_three[0] = calories;
+            top_three.sort();
+        }
+    }
+
+    top_three.iter().sum()
+}
@@ -0,0 +1,139 @@
+use std::ops::Div;
+
+use crate::{
+    cli::Part,
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const STREAMING: bool = true;
+
+    type Input = Vec<Operation>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(parse_input(input.lines()).collect())
+    }
+
+    fn part_one(&self, ops: &Self::Input) -> Answer {
+        signal_strengths(ops.iter().copied()).into()
+    }
+
+    fn part_two(&self, ops: &Self::Input) -> Answer {
+        render_crt(&run_crt(ops.iter().copied())).into()
+    }
+
+    fn stream_part(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        let ops = parse_input(lines).map(expect_valid);
+        match part {
+            Part::One => signal_strengths(ops).into(),
+            Part::Two => render_crt(&run_crt(ops)).into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Operation {
+    Noop,
+    Addx(i32),
+}
+
+const IMPORTANT_CYCLES: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+fn maybe_save_signal(signals: &mut Vec<i32>, cycle: i32, x_register: i32) {
+    if IMPORTANT_CYCLES.contains(&cycle) {
+        signals.push(cycle * x_register);
+    }
+}
+
+fn signal_strengths(ops: impl Iterator<Item = Operation>) -> i32 {
+    let mut x_register = 1;
+    let mut current_cycle = 0;
+    let mut signals: Vec<i32> = vec![];
+
+    // the signal is taken during its cycle, before an addx finishes, so
+    // cycles after the end of the program have none
+    ops.for_each(|op| match op {
+        Operation::Noop => {
+            current_cycle += 1;
+            maybe_save_signal(&mut signals, current_cycle, x_register);
+        }
+        Operation::Addx(value) => {
+            current_cycle += 1;
+            maybe_save_signal(&mut signals, current_cycle, x_register);
+
+            current_cycle += 1;
+            maybe_save_signal(&mut signals, current_cycle, x_register);
+            x_register += value;
+        }
+    });
+
+    signals.iter().sum()
+}
+
+// ---------------------
+type Crt = [[char; 40]; 6];
+
+/// The screen as text, one line per row.
+fn render_crt(crt: &Crt) -> String {
+    crt.iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Pixels past the last row are off the screen and not drawn.
+fn maybe_draw_pixel_on_crt(current_pixel: i32, sprite_mid: i32, crt: &mut Crt) {
+    let row_idx = current_pixel.div(40);
+    let column_idx = current_pixel % 40;
+    let Some(row) = crt.get_mut(row_idx as usize) else {
+        return;
+    };
+    if (sprite_mid - column_idx).abs() <= 1 {
+        row[column_idx as usize] = '#';
+    }
+}
+
+fn run_crt(ops: impl Iterator<Item = Operation>) -> Crt {
+    let mut x_register = 1;
+    let mut current_cycle = 0;
+
+    let mut crt: Crt = [['.'; 40]; 6];
+
+    // a pixel is drawn during its cycle, before an addx finishes
+    ops.for_each(|op| match op {
+        Operation::Noop => {
+            maybe_draw_pixel_on_crt(current_cycle, x_register, &mut crt);
+            current_cycle += 1;
+        }
+        Operation::Addx(value) => {
+            maybe_draw_pixel_on_crt(current_cycle, x_register, &mut crt);
+            current_cycle += 1;
+
+            maybe_draw_pixel_on_crt(current_cycle, x_register, &mut crt);
+            current_cycle += 1;
+            x_register += value;
+        }
+    });
+
+    crt
+}
+
+pub fn parse_input<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+) -> impl Iterator<Item = Result<Operation, ParseError>> {
+    lines.into_iter().enumerate().map(|(idx, line)| {
+        let tokens: Vec<&str> = line.as_ref().split_ascii_whitespace().collect();
+        match tokens[..] {
+            ["noop"] => Ok(Operation::Noop),
+            ["addx", value] => value
+                .parse()
+                .map(Operation::Addx)
+                .map_err(|err| ParseError::from(err).on_line(idx)),
+            _ => Err(ParseError::new("expected noop or addx").on_line(idx)),
+        }
+    })
+}
@@ -0,0 +1,165 @@
+use crate::{
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    /// both letters of every row of the strategy guide
+    type Input = Vec<(char, char)>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(parse_input(input))
+    }
+
+    fn part_one(&self, rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|(opponent, ours)| {
+                (
+                    expect_valid(Shape::from_char(*opponent)),
+                    expect_valid(Shape::from_char(*ours)),
+                )
+            })
+            .fold(0, |acc, (opponent_shape, our_shape)| {
+                acc + our_shape.to_score() + our_shape.play_round(&opponent_shape).to_score()
+            })
+            .into()
+    }
+
+    fn part_two(&self, rows: &Self::Input) -> Answer {
+        rows.iter()
+            .map(|(opponent, result)| {
+                (
+                    expect_valid(Shape::from_char(*opponent)),
+                    expect_valid(RoundResult::from_char(*result)),
+                )
+            })
+            .fold(0, |acc, (opponent_shape, result)| {
+                let our_shape = result.to_shape(&opponent_shape);
+                acc + our_shape.to_score() + our_shape.play_round(&opponent_shape).to_score()
+            })
+            .into()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RoundResult {
+    Won,
+    Draw,
+    Lost,
+}
+impl RoundResult {
+    fn to_score(&self) -> i32 {
+        match self {
+            RoundResult::Won => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Lost => 0,
+        }
+    }
+
+    pub fn from_char(letter: char) -> Result<Self, ParseError> {
+        match letter {
+            'X' => Ok(Self::Lost),
+            'Y' => Ok(Self::Draw),
+            'Z' => Ok(Self::Won),
+            _ => Err(ParseError::new(format!(
+                "invalid round result {:?}",
+                letter
+            ))),
+        }
+    }
+
+    fn to_shape(&self, opponent: &Shape) -> Shape {
+        match opponent {
+            Shape::Rock => match self {
+                RoundResult::Won => Shape::Paper,
+                RoundResult::Draw => Shape::Rock,
+                RoundResult::Lost => Shape::Scissors,
+            },
+            Shape::Paper => match self {
+                RoundResult::Won => Shape::Scissors,
+                RoundResult::Draw => Shape::Paper,
+                RoundResult::Lost => Shape::Rock,
+            },
+            Shape::Scissors => match self {
+                RoundResult::Won => Shape::Rock,
+                RoundResult::Draw => Shape::Scissors,
+                RoundResult::Lost => Shape::Paper,
+            },
+        }
+    }
+}
+
+impl Shape {
+    pub fn from_char(letter: char) -> Result<Self, ParseError> {
+        match letter {
+            'A' => Ok(Self::Rock),
+            'X' => Ok(Self::Rock),
+
+            'B' => Ok(Self::Paper),
+            'Y' => Ok(Self::Paper),
+
+            'C' => Ok(Self::Scissors),
+            'Z' => Ok(Self::Scissors),
+
+            _ => Err(ParseError::new(format!("invalid shape {:?}", letter))),
+        }
+    }
+
+    fn to_score(&self) -> i32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    fn play_round(&self, other: &Shape) -> RoundResult {
+        match self {
+            Shape::Rock => match other {
+                Shape::Rock => RoundResult::Draw,
+                Shape::Paper => RoundResult::Lost,
+                Shape::Scissors => RoundResult::Won,
+            },
+            Shape::Paper => match other {
+                Shape::Rock => RoundResult::Won,
+                Shape::Paper => RoundResult::Draw,
+                Shape::Scissors => RoundResult::Lost,
+            },
+            Shape::Scissors => match other {
+                Shape::Rock => RoundResult::Lost,
+                Shape::Paper => RoundResult::Won,
+                Shape::Scissors => RoundResult::Draw,
+            },
+        }
+    }
+}
+
+/// The two letters of every row. The opponent's is a shape, ours is a shape
+/// or a round result depending on the part, so both are checked.
+pub fn parse_input(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let letters: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+            let &[opponent, ours] = &letters[..] else {
+                return Err(ParseError::new("expected two letters").on_line(idx));
+            };
+
+            Shape::from_char(opponent).map_err(|err| err.on_line(idx))?;
+            RoundResult::from_char(ours).map_err(|err| err.on_line(idx))?;
+            Ok((opponent, ours))
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use std::{char, collections::HashSet};
+
+use crate::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
+
+    fn part_one(&self, rucksacks: &Self::Input) -> Answer {
+        rucksacks
+            .iter()
+            .map(|line| {
+                let (a, b) = split_rucksack_into_compartments(line.into());
+                let common_el = find_common_el(vec![a, b]);
+                char_to_priority(common_el)
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(&self, rucksacks: &Self::Input) -> Answer {
+        let mut sum = 0;
+        for i in (0..rucksacks.len()).step_by(3) {
+            let ls = rucksacks[i..i + 3].to_vec();
+            let c = find_common_el(ls);
+            sum += char_to_priority(c);
+        }
+
+        sum.into()
+    }
+}
+
+fn split_rucksack_into_compartments(rucksack: String) -> (String, String) {
+    let (a, b) = rucksack.split_at(rucksack.len() / 2);
+    (a.into(), b.into())
+}
+
+fn find_common_el(xs: Vec<String>) -> char {
+    let sets: Vec<HashSet<char>> = xs.iter().map(|s| s.chars().collect()).collect();
+
+    let common_chars: Vec<&char> = sets[0]
+        .iter()
+        .filter(|c| sets[1..].iter().all(|s| s.contains(c)))
+        .collect();
+    *common_chars.first().unwrap().to_owned()
+}
+
+fn char_to_priority(c: char) -> u32 {
+    match c.is_lowercase() {
+        true => c as u32 - 96,       // 'a' is 97
+        false => c as u32 - 64 + 26, // 'A' is 65
+    }
+}
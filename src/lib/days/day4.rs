@@ -0,0 +1,138 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    cli::Part,
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const STREAMING: bool = true;
+
+    type Input = Vec<Assignment>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(get_assignments(input.lines()).collect())
+    }
+
+    fn part_one(&self, assignments: &Self::Input) -> Answer {
+        count_fully_overlapping(assignments.iter().cloned()).into()
+    }
+
+    fn part_two(&self, assignments: &Self::Input) -> Answer {
+        count_overlapping(assignments.iter().cloned()).into()
+    }
+
+    fn stream_part(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        let assignments = get_assignments(lines).map(expect_valid);
+        match part {
+            Part::One => count_fully_overlapping(assignments).into(),
+            Part::Two => count_overlapping(assignments).into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Intersection {
+    Bellow,
+    BellowOverlap,
+    Contained,
+    Contains,
+    Same,
+    AboveOverlap,
+    Above,
+}
+
+trait Intersect {
+    fn intersect(&self, other: &RangeInclusive<i32>) -> Intersection;
+}
+
+impl Intersect for RangeInclusive<i32> {
+    fn intersect(&self, other: &RangeInclusive<i32>) -> Intersection {
+        let self_min = self.clone().min().unwrap();
+        let self_max = self.clone().max().unwrap();
+
+        let other_min = other.clone().min().unwrap();
+        let other_max = other.clone().max().unwrap();
+
+        if self_max < other_min {
+            return Intersection::Bellow;
+        }
+
+        if self_min > other_max {
+            return Intersection::Above;
+        }
+
+        if self_min == other_min && self_max == other_max {
+            return Intersection::Same;
+        }
+
+        if self_min >= other_min && self_max <= other_max {
+            return Intersection::Contained;
+        }
+
+        if self_min <= other_min && self_max >= other_max {
+            return Intersection::Contains;
+        }
+
+        // the only cases left are BellowOverlap and AboveOverlap
+        // so we can just simply compare two min values:
+        if self_min < other_min {
+            Intersection::BellowOverlap
+        } else {
+            Intersection::AboveOverlap
+        }
+    }
+}
+
+pub fn section_to_assignment(s: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(format!("expected a range, got {:?}", s)))?;
+    let start = start.parse::<i32>()?;
+    let end = end.parse::<i32>()?;
+
+    // empty ranges have no min and max to intersect
+    if start > end {
+        return Err(ParseError::new(format!("empty range {:?}", s)));
+    }
+    Ok(start..=end)
+}
+
+pub type Assignment = (RangeInclusive<i32>, RangeInclusive<i32>);
+
+pub fn get_assignments<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+) -> impl Iterator<Item = Result<Assignment, ParseError>> {
+    lines.into_iter().enumerate().map(|(idx, line)| {
+        let parse_line = || {
+            let (s1, s2) = line
+                .as_ref()
+                .split_once(',')
+                .ok_or_else(|| ParseError::new("expected two ranges"))?;
+
+            Ok((section_to_assignment(s1)?, section_to_assignment(s2)?))
+        };
+        parse_line().map_err(|err: ParseError| err.on_line(idx))
+    })
+}
+
+fn count_fully_overlapping(assignments: impl Iterator<Item = Assignment>) -> usize {
+    assignments
+        .filter(|(r1, r2)| {
+            matches!(
+                r1.intersect(r2),
+                Intersection::Contained | Intersection::Contains | Intersection::Same
+            )
+        })
+        .count()
+}
+
+fn count_overlapping(assignments: impl Iterator<Item = Assignment>) -> usize {
+    assignments
+        .filter(|(r1, r2)| !matches!(r1.intersect(r2), Intersection::Above | Intersection::Bellow))
+        .count()
+}
@@ -0,0 +1,219 @@
+use crate::{
+    io_utils::pad_rows,
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub type Crate = Vec<char>;
+pub type Crates = Vec<Crate>;
+
+#[derive(Debug)]
+pub struct Instruction {
+    quantity: usize,
+    from: usize,
+    to: usize,
+}
+
+pub type Instructions = Vec<Instruction>;
+
+enum MovingStrategy {
+    StackLike,
+    AtOnce,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = (Crates, Instructions);
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(input_to_domain(input))
+    }
+
+    fn part_one(&self, (crates, instructions): &Self::Input) -> Answer {
+        let mut crates = crates.clone();
+
+        for instr in instructions {
+            move_crates(&mut crates, instr, &MovingStrategy::StackLike);
+        }
+
+        top_crates(&crates).into()
+    }
+
+    fn part_two(&self, (crates, instructions): &Self::Input) -> Answer {
+        let mut crates = crates.clone();
+
+        for instr in instructions {
+            move_crates(&mut crates, instr, &MovingStrategy::AtOnce);
+        }
+
+        top_crates(&crates).into()
+    }
+}
+
+fn move_crates(crates: &mut Crates, instr: &Instruction, moving_strategy: &MovingStrategy) {
+    let mut moving_els = vec![];
+    for _ in 0..instr.quantity {
+        let c = crates[instr.from].pop().unwrap();
+        moving_els.push(c);
+    }
+
+    match *moving_strategy {
+        MovingStrategy::StackLike => {
+            for c in moving_els {
+                crates[instr.to].push(c);
+            }
+        }
+        MovingStrategy::AtOnce => {
+            for c in moving_els.iter().rev() {
+                crates[instr.to].push(*c);
+            }
+        }
+    }
+}
+
+/// The crate on top of every stack that isn't empty.
+fn top_crates(crates: &Crates) -> String {
+    crates.iter().filter_map(|stack| stack.last()).collect()
+}
+
+// parsing -------------------------------------------------------
+
+fn split_input(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let split = input
+        .lines()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+
+    let mut split = split.split(|line| line.is_empty());
+
+    // rows of the diagram may have lost their trailing spaces
+    let crates_part = pad_rows(split.next().unwrap_or_default());
+    let instructions_part = split
+        .next()
+        .ok_or_else(|| ParseError::new("expected the moves after an empty line"))?
+        .into();
+    Ok((crates_part, instructions_part))
+}
+
+fn get_crates_from_input(input: &[String]) -> Result<Crates, ParseError> {
+    let height = input.len();
+    if height < 2 {
+        return Err(ParseError::new("expected crates above the stack numbers"));
+    }
+    let width = input[0].chars().count();
+
+    let mut crates = vec![];
+
+    // step by 4 to skip "] ["
+    for column in (1..width).step_by(4) {
+        let mut curr_crate = vec![];
+
+        // skip the last row, since it is the one containing labels
+        for row in (0..height - 1).rev() {
+            // rows are padded to the same width
+            let maybe_crate = input[row].chars().nth(column).unwrap_or(' ');
+            if maybe_crate != ' ' {
+                curr_crate.push(maybe_crate);
+            }
+        }
+
+        crates.push(curr_crate.clone());
+    }
+
+    Ok(crates)
+}
+
+/// Moves that take more crates than there are on a stack are rejected.
+/// `first_line` is the index of the first move in the whole input, for
+/// reporting errors.
+fn get_instructions_from_input(
+    input: &[String],
+    crates: &Crates,
+    first_line: usize,
+) -> Result<Instructions, ParseError> {
+    let mut heights: Vec<usize> = crates.iter().map(Vec::len).collect();
+    let stacks = heights.len();
+    let stack_idx = |number: &str| -> Result<usize, ParseError> {
+        match number.parse::<usize>()? {
+            number @ 1.. if number <= stacks => Ok(number - 1),
+            number => Err(ParseError::new(format!("there is no stack {}", number))),
+        }
+    };
+
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let idx = first_line + idx;
+            let words: Vec<&str> = line.split_ascii_whitespace().collect();
+            let ["move", quantity, "from", from, "to", to] = words[..] else {
+                return Err(ParseError::new("expected a move").on_line(idx));
+            };
+
+            let mut parse_move = || {
+                let instr = Instruction {
+                    quantity: quantity.parse()?,
+                    from: stack_idx(from)?,
+                    to: stack_idx(to)?,
+                };
+                if instr.quantity > heights[instr.from] {
+                    return Err(ParseError::new(format!(
+                        "stack {} only has {} crates",
+                        from, heights[instr.from]
+                    )));
+                }
+                heights[instr.from] -= instr.quantity;
+                heights[instr.to] += instr.quantity;
+                Ok(instr)
+            };
+            parse_move().map_err(|err: ParseError| err.on_line(idx))
+        })
+        .collect()
+}
+
+/// The stacks of crates, bottom first, and the moves rearranging them.
+pub fn input_to_domain(input: &str) -> Result<(Crates, Instructions), ParseError> {
+    let (crates_input, instructions_input) = split_input(input)?;
+    let crates = get_crates_from_input(&crates_input)?;
+    // the moves start after the diagram and the empty line
+    let instructions =
+        get_instructions_from_input(&instructions_input, &crates, crates_input.len() + 1)?;
+    Ok((crates, instructions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_to_domain, Day5};
+    use crate::Solution;
+
+    #[test]
+    fn errors_report_the_line_in_the_whole_input() {
+        let test_cases = vec![
+            (
+                "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove x from 1 to 2",
+                6,
+            ),
+            ("[A]\n 1\n\nmove 1 from 1 to 2", 4),
+            ("[A]\n 1\n\nmove 1 from 1 to 1\njump", 5),
+            (
+                "[A]    \n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1",
+                5,
+            ),
+        ];
+
+        for (input, line) in test_cases {
+            let err = input_to_domain(input).unwrap_err();
+            assert_eq!(err.line, Some(line), "{}", err);
+        }
+    }
+
+    #[test]
+    fn empty_stacks_have_no_top_crate() {
+        let input = Day5.parse("[A]    \n 1   2\n\nmove 1 from 1 to 2");
+        assert_eq!(Day5.part_one(&input).to_string(), "A");
+        assert_eq!(Day5.part_two(&input).to_string(), "A");
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{Answer, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    /// the datastream buffer
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Answer {
+        find_first_marker_idx(input, 4).into()
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Answer {
+        find_first_marker_idx(input, 14).into()
+    }
+}
+
+fn find_first_marker_idx(input: &str, unique_fragment_len: usize) -> usize {
+    let mut acc = vec![];
+    let mut idx = 1;
+    for c in input.chars() {
+        if acc.contains(&c) {
+            // rm elements before the duplicate (including the duplicate)
+            acc = acc.split(|el| el == &c).nth(1).unwrap().to_vec();
+            acc.push(c);
+        } else {
+            acc.push(c);
+            if acc.len() == unique_fragment_len {
+                break;
+            }
+        }
+
+        idx += 1;
+    }
+
+    idx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_first_marker_idx;
+
+    #[test]
+    fn example_tests_4() {
+        let test_cases = vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ];
+
+        for (input, answer) in test_cases {
+            assert_eq!(find_first_marker_idx(input, 4), answer);
+        }
+    }
+
+    #[test]
+    fn example_tests_14() {
+        let test_cases = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ];
+
+        for (input, answer) in test_cases {
+            assert_eq!(find_first_marker_idx(input, 14), answer);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    parse::{expect_valid, ParseError},
+    tree::{NodeId, NodeValues, Tree},
+    Answer, Solution,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        create_fs(&expect_valid(parse_input(input)))
+    }
+
+    fn part_one(&self, fs: &Self::Input) -> Answer {
+        fs.dir_sizes()
+            .filter(|size| *size <= 100_000)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self, fs: &Self::Input) -> Answer {
+        let root_dir_size = fs.sizes[&fs.root];
+        let free = FILE_SYSTEM_SIZE.saturating_sub(root_dir_size);
+        // nothing has to be deleted if there is already enough free space
+        let difference = MIN_SIZE_TO_FREE.saturating_sub(free);
+        if difference == 0 {
+            return 0.into();
+        }
+
+        // the root itself is always big enough
+        fs.dir_sizes()
+            .filter(|size| size >= &difference)
+            .min()
+            .unwrap_or(root_dir_size)
+            .into()
+    }
+}
+
+const FILE_SYSTEM_SIZE: usize = 70000000;
+const MIN_SIZE_TO_FREE: usize = 30000000;
+
+#[derive(Debug)]
+pub enum Command {
+    ChangeDir(String),
+    MoveToRootDir,
+    MoveUp,
+    List(Vec<SingleListOutput>),
+}
+
+#[derive(Debug)]
+pub enum SingleListOutput {
+    File { size: usize },
+    Dir(String),
+}
+
+#[derive(Debug)]
+pub struct FileSystem {
+    root: NodeId,
+    /// total size of every directory, including its subdirectories
+    sizes: NodeValues<usize>,
+}
+
+impl FileSystem {
+    /// Total size of every directory, in the order they were found.
+    fn dir_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.sizes.values().copied()
+    }
+}
+
+#[derive(Debug)]
+struct Directory {
+    name: String,
+    files: Vec<File>,
+}
+
+impl Directory {
+    fn new(name: String) -> Self {
+        Self {
+            files: vec![],
+            name,
+        }
+    }
+
+    fn add_file(&mut self, file: File) {
+        self.files.push(file);
+    }
+
+    /// size of the files directly in the directory
+    fn files_size(&self) -> usize {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    size: usize,
+}
+
+impl File {
+    fn new(size: usize) -> Self {
+        Self { size }
+    }
+}
+
+fn create_fs(commands: &[Command]) -> FileSystem {
+    let mut t = Tree::default();
+    let root_dir = t.add_node(None, Directory::new("/".to_string()));
+    let mut curr_dir = root_dir;
+
+    for cmd in commands {
+        match cmd {
+            Command::ChangeDir(dir_name) => {
+                for child in t.get_node(&curr_dir).unwrap().children.iter() {
+                    if &t.get_node(child).unwrap().data.name == dir_name {
+                        curr_dir = *child;
+                        break;
+                    }
+                }
+            }
+            Command::MoveToRootDir => {
+                curr_dir = root_dir;
+            }
+            Command::MoveUp => {
+                // like in a shell, `cd ..` in the root stays there
+                if let Some(parent_id) = t.get_node(&curr_dir).unwrap().parent {
+                    curr_dir = parent_id;
+                }
+            }
+            Command::List(outputs) => {
+                for output in outputs {
+                    match output {
+                        &SingleListOutput::File { size } => {
+                            t.update_node(curr_dir, &|node| {
+                                node.data.add_file(File::new(size));
+                            });
+                        }
+                        SingleListOutput::Dir(dir_name) => {
+                            t.add_node(Some(curr_dir), Directory::new(dir_name.to_string()));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let sizes = t.fold_subtrees(root_dir, |dir, subdirs| {
+        dir.files_size() + subdirs.iter().copied().sum::<usize>()
+    });
+    FileSystem {
+        root: root_dir,
+        sizes,
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut input = input.lines().enumerate().peekable();
+    let mut res = vec![];
+
+    while let Some((idx, line)) = input.next() {
+        let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+        match tokens[..] {
+            ["$", "cd", "/"] => res.push(Command::MoveToRootDir),
+            ["$", "cd", ".."] => res.push(Command::MoveUp),
+            ["$", "cd", dir_name] => res.push(Command::ChangeDir(dir_name.to_string())),
+            ["$", "ls"] => {
+                let mut outputs = vec![];
+                while let Some((idx, out)) = input.next_if(|(_, l)| !l.starts_with('$')) {
+                    match out.split_ascii_whitespace().collect::<Vec<_>>()[..] {
+                        ["dir", dir_name] => {
+                            outputs.push(SingleListOutput::Dir(dir_name.to_string()))
+                        }
+                        [size, _] => outputs.push(SingleListOutput::File {
+                            size: size
+                                .parse()
+                                .map_err(|err| ParseError::from(err).on_line(idx))?,
+                        }),
+                        _ => {
+                            return Err(
+                                ParseError::new("expected a file or a directory").on_line(idx)
+                            )
+                        }
+                    }
+                }
+                res.push(Command::List(outputs));
+            }
+            _ => return Err(ParseError::new(format!("unexpected command {:?}", line)).on_line(idx)),
+        }
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+    use crate::Solution;
+
+    #[test]
+    fn transcript_ending_deep_in_the_tree() {
+        // never goes back up, so every size must come from the whole tree
+        let input = "$ cd /\n$ ls\ndir a\n100 x\n$ cd a\n$ ls\ndir b\n200 y\n$ cd b\n$ ls\n300 z";
+        let fs = Day7.parse(input);
+        assert_eq!(Day7.part_one(&fs).to_string(), "1400");
+        assert_eq!(Day7.part_two(&fs).to_string(), "0");
+
+        let input = "$ cd /\n$ ls\ndir a\n100 x\n$ cd a\n$ ls\n40000000 y";
+        let fs = Day7.parse(input);
+        assert_eq!(Day7.part_two(&fs).to_string(), "40000000");
+    }
+
+    #[test]
+    fn full_disk() {
+        let fs = Day7.parse("$ cd /\n$ ls\ndir a\n80000000 x\n$ cd a\n$ ls\n10 y");
+        assert_eq!(Day7.part_two(&fs).to_string(), "80000010");
+    }
+}
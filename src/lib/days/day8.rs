@@ -0,0 +1,216 @@
+use crate::{
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub type Matrix2D<T> = Vec<Vec<T>>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    /// heights of the trees
+    type Input = Matrix2D<i32>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(parse_input(input))
+    }
+
+    fn part_one(&self, data: &Self::Input) -> Answer {
+        count_visible(data).into()
+    }
+
+    fn part_two(&self, data: &Self::Input) -> Answer {
+        max_scenic_score(data).into()
+    }
+}
+
+fn count_visible(data: &Matrix2D<i32>) -> usize {
+    let (left, top, right, bottom) = precalculate_max_hights(data);
+
+    let width = data[0].len();
+    let height = data.len();
+
+    let mut visible = 0;
+    for y in 1..height.saturating_sub(1) {
+        for x in 1..width.saturating_sub(1) {
+            if [left[y][x], top[y][x], right[y][x], bottom[y][x]]
+                .iter()
+                .any(|el| el < &data[y][x])
+            {
+                visible += 1;
+            }
+        }
+    }
+    // every tree on the edge is visible
+    visible += width * height - width.saturating_sub(2) * height.saturating_sub(2);
+
+    visible
+}
+
+/// The highest tree between every tree and the edge, in each direction,
+/// indexed like the grid: `[y][x]`.
+fn precalculate_max_hights(
+    data: &Matrix2D<i32>,
+) -> (Matrix2D<i32>, Matrix2D<i32>, Matrix2D<i32>, Matrix2D<i32>) {
+    let width = data[0].len();
+    let height = data.len();
+
+    let mut max_value = 0;
+
+    let mut max_heights_left = vec![vec![0; width]; height];
+
+    for y in 0..height {
+        for x in 0..width {
+            if x == 0 {
+                max_value = 0;
+            }
+
+            max_heights_left[y][x] = max_value;
+            max_value = max_value.max(data[y][x]);
+        }
+    }
+
+    let mut max_heights_right = vec![vec![0; width]; height];
+
+    for y in 0..height {
+        for x in (0..width).rev() {
+            if x == width - 1 {
+                max_value = 0;
+            }
+
+            max_heights_right[y][x] = max_value;
+            max_value = max_value.max(data[y][x]);
+        }
+    }
+
+    let mut max_heights_top = vec![vec![0; width]; height];
+
+    for x in 0..width {
+        for y in 0..height {
+            if y == 0 {
+                max_value = 0;
+            }
+
+            max_heights_top[y][x] = max_value;
+            max_value = max_value.max(data[y][x]);
+        }
+    }
+
+    let mut max_heights_bottom = vec![vec![0; width]; height];
+
+    for x in 0..width {
+        for y in (0..height).rev() {
+            if y == height - 1 {
+                max_value = 0;
+            }
+
+            max_heights_bottom[y][x] = max_value;
+            max_value = max_value.max(data[y][x]);
+        }
+    }
+
+    (
+        max_heights_left,
+        max_heights_top,
+        max_heights_right,
+        max_heights_bottom,
+    )
+}
+
+fn max_scenic_score(data: &Matrix2D<i32>) -> usize {
+    let width = data[0].len();
+    let height = data.len();
+    let mut max_scenic_score = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let tree_height = data[y][x];
+            let mut score = (0, 0, 0, 0);
+
+            for i in (0..x).rev() {
+                score.0 += 1;
+                if data[y][i] >= tree_height {
+                    break;
+                }
+            }
+            for other_height in &data[y][(x + 1)..width] {
+                score.1 += 1;
+                if *other_height >= tree_height {
+                    break;
+                }
+            }
+            for i in (0..y).rev() {
+                score.2 += 1;
+                if data[i][x] >= tree_height {
+                    break;
+                }
+            }
+            for row in &data[(y + 1)..height] {
+                score.3 += 1;
+                if row[x] >= tree_height {
+                    break;
+                }
+            }
+
+            let local_scenic_score = score.0 * score.1 * score.2 * score.3;
+            if local_scenic_score > max_scenic_score {
+                max_scenic_score = local_scenic_score;
+            }
+        }
+    }
+
+    max_scenic_score
+}
+
+/// The grid of tree heights.
+pub fn parse_input(input: &str) -> Result<Matrix2D<i32>, ParseError> {
+    let data: Matrix2D<i32> = input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(height) => Ok(height as i32),
+                    None => Err(ParseError::new(format!("invalid height {:?}", c)).on_line(idx)),
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    if data.first().is_none_or(|row| row.is_empty()) {
+        return Err(ParseError::new("expected a grid"));
+    }
+    if let Some(idx) = data.iter().position(|row| row.len() != data[0].len()) {
+        return Err(ParseError::new("expected rows of the same length").on_line(idx));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_visible, max_scenic_score, parse_input};
+
+    #[test]
+    fn rectangular_grids() {
+        let test_cases = vec![
+            ("30373\n25512\n65332", 14, 2),
+            ("326\n055\n353\n713\n322", 14, 2),
+            ("12345", 5, 0),
+        ];
+
+        for (input, visible, scenic_score) in test_cases {
+            let data = parse_input(input).unwrap();
+            assert_eq!(count_visible(&data), visible, "{}", input);
+            assert_eq!(max_scenic_score(&data), scenic_score, "{}", input);
+        }
+    }
+
+    #[test]
+    fn invalid_grids() {
+        assert_eq!(parse_input("123\n45").unwrap_err().line, Some(2));
+        assert!(parse_input("").is_err());
+        assert!(parse_input("\n123").is_err());
+    }
+}
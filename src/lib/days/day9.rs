@@ -0,0 +1,131 @@
+use std::{
+    collections::HashSet,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    cli::Part,
+    parse::{expect_valid, ParseError},
+    Answer, Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const STREAMING: bool = true;
+
+    type Input = Vec<Motion>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        expect_valid(parse_input(input.lines()).collect())
+    }
+
+    fn part_one(&self, motions: &Self::Input) -> Answer {
+        simulation_interpreter(motions.iter().copied(), 2).into()
+    }
+
+    fn part_two(&self, motions: &Self::Input) -> Answer {
+        simulation_interpreter(motions.iter().copied(), 10).into()
+    }
+
+    fn stream_part(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        let ropes_num = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+
+        simulation_interpreter(parse_input(lines).map(expect_valid), ropes_num).into()
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
+pub struct Coord(i32, i32);
+
+impl Add for Coord {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Coord(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl TryFrom<&str> for Coord {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "R" => Ok(Coord(1, 0)),
+            "U" => Ok(Coord(0, 1)),
+            "L" => Ok(Coord(-1, 0)),
+            "D" => Ok(Coord(0, -1)),
+            _ => Err(ParseError::new(format!("unexpected direction {:?}", s))),
+        }
+    }
+}
+
+impl Coord {
+    fn new() -> Self {
+        Self(0, 0)
+    }
+}
+
+fn move_head(state: Coord, direction: Coord) -> Coord {
+    state + direction
+}
+
+/// move one position towards the other with the algorithm
+/// as specified in the task
+fn move_toward(position: Coord, other_position: Coord) -> Coord {
+    let delta = other_position - position;
+    if delta.0.abs() <= 1 && delta.1.abs() <= 1 {
+        position
+    } else {
+        let tail_move = Coord(delta.0.signum(), delta.1.signum());
+        position + tail_move
+    }
+}
+
+fn simulation_interpreter(series: impl IntoIterator<Item = Motion>, ropes_num: usize) -> usize {
+    let mut tail_positions_set: HashSet<Coord> = HashSet::new();
+
+    // current_positions[0] is head
+    // current_positions[n - 1] is tail
+    let mut current_positions = vec![Coord::new(); ropes_num];
+
+    for (dir, repeat_times) in series {
+        for _ in 0..repeat_times {
+            current_positions[0] = move_head(current_positions[0], dir);
+            for rope_idx in 1..ropes_num {
+                current_positions[rope_idx] =
+                    move_toward(current_positions[rope_idx], current_positions[rope_idx - 1]);
+            }
+
+            tail_positions_set.insert(current_positions[ropes_num - 1]);
+        }
+    }
+
+    tail_positions_set.len()
+}
+
+pub type Motion = (Coord, usize);
+
+pub fn parse_input<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = S>,
+) -> impl Iterator<Item = Result<Motion, ParseError>> {
+    lines.into_iter().enumerate().map(|(idx, line)| {
+        let parse_line = || {
+            let Some((direction, steps)) = line.as_ref().split_once(' ') else {
+                return Err(ParseError::new("expected a direction and steps"));
+            };
+            Ok((Coord::try_from(direction)?, steps.parse()?))
+        };
+        parse_line().map_err(|err: ParseError| err.on_line(idx))
+    })
+}
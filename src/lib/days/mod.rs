@@ -0,0 +1,242 @@
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::DynSolution;
+
+/// Every solved day, in order.
+pub static DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+];
+
+pub fn get(day: u32) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{get, DAYS};
+    use crate::{
+        answers::Answers,
+        cli::Part,
+        gen::generator,
+        io_utils::{
+            read_example, read_example_for_day, try_read_input_for_day, try_read_input_from_path,
+            Example, InputError,
+        },
+        oracle::oracle,
+        runner::run_part,
+        scaffold::new_day,
+        Answer, DynSolution, Solution,
+    };
+
+    /// What is wrong with the list of registered days, if anything: they must
+    /// be distinct puzzle days, in order.
+    fn registry_problem(days: &[u32]) -> Option<String> {
+        if let Some(day) = days.iter().find(|day| !(1..=25).contains(*day)) {
+            return Some(format!("day {} is not a puzzle day", day));
+        }
+        if let Some(pair) = days.windows(2).find(|pair| pair[0] >= pair[1]) {
+            return Some(format!(
+                "day {} is registered after day {}",
+                pair[1], pair[0]
+            ));
+        }
+        None
+    }
+
+    /// Runs every example with published answers. Days whose examples aren't
+    /// filled in yet are skipped. Returns the failures.
+    fn example_failures(
+        solutions: &[&dyn DynSolution],
+        read_example: impl Fn(u32, u32) -> Result<Example, InputError>,
+    ) -> Vec<String> {
+        let mut failures = vec![];
+
+        for solution in solutions {
+            let day = solution.day();
+            for n in 1.. {
+                let example = match read_example(day, n) {
+                    Ok(example) => example,
+                    Err(InputError::Unresolved { .. } | InputError::Empty { .. }) => break,
+                    Err(err) => panic!("{}", err),
+                };
+
+                let expected = [(Part::One, example.part_one), (Part::Two, example.part_two)];
+                for (part, expected) in expected {
+                    if let Some(expected) = expected {
+                        let actual = solution.run(part, &example.input).to_string();
+                        if actual != expected {
+                            failures.push(format!(
+                                "Day {} example {} {}: expected {}, got {}",
+                                day, n, part, expected, actual
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        failures
+    }
+
+    /// Runs every part with a recorded answer on the day's input, both parsed
+    /// and streamed. Days without an input yet are skipped. Returns the
+    /// failures.
+    fn answer_failures(
+        solutions: &[&dyn DynSolution],
+        answers: &Answers,
+        read_input: impl Fn(u32) -> Result<String, InputError>,
+    ) -> Vec<String> {
+        let mut failures = vec![];
+
+        for solution in solutions {
+            let day = solution.day();
+            let input = match read_input(day) {
+                Ok(input) => input,
+                Err(InputError::Empty { .. }) => {
+                    eprintln!("Day {}: no input yet", day);
+                    continue;
+                }
+                Err(err) => panic!("{}", err),
+            };
+            let parsed = solution.parse_any(&input);
+
+            for part in Part::ALL {
+                let Some(expected) = answers.get(day, part) else {
+                    eprintln!("Day {} {}: no recorded answer", day, part);
+                    continue;
+                };
+
+                let actual = solution.run_parsed(part, parsed.as_ref()).to_string();
+                let streamed = solution
+                    .run_lines(part, &mut input.lines().map(String::from))
+                    .to_string();
+                for (how, actual) in [("parsed", actual), ("streamed", streamed)] {
+                    if actual != expected {
+                        failures.push(format!(
+                            "Day {} {} ({}): expected {}, got {}",
+                            day, part, how, expected, actual
+                        ));
+                    }
+                }
+            }
+        }
+
+        failures
+    }
+
+    #[test]
+    fn test_registry() {
+        let days: Vec<u32> = DAYS.iter().map(|solution| solution.day()).collect();
+        assert_eq!(registry_problem(&days), None, "{:?}", days);
+        assert!(get(4).is_some());
+        assert!(get(25).is_none());
+
+        assert!(registry_problem(&[1, 3, 2]).is_some());
+        assert!(registry_problem(&[1, 1]).is_some());
+        assert!(registry_problem(&[26]).is_some());
+    }
+
+    #[test]
+    fn test_examples() {
+        let failures = example_failures(DAYS, read_example_for_day);
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_answers() {
+        let answers = Answers::load().unwrap();
+        let failures = answer_failures(DAYS, &answers, try_read_input_for_day);
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    /// Day 25 the way `aoc new 25` leaves it.
+    struct Scaffolded;
+
+    impl Solution for Scaffolded {
+        const DAY: u32 = 25;
+
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Self::Input {
+            input.lines().map(String::from).collect()
+        }
+
+        fn part_one(&self, _input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn part_two(&self, _input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_scaffolded_day_passes_harnesses() {
+        let root = env::temp_dir().join(format!("aoc_scaffold_harness_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/lib/days")).unwrap();
+        fs::write(root.join("src/lib/days/mod.rs"), include_str!("mod.rs")).unwrap();
+        new_day(Scaffolded::DAY, &root).unwrap();
+
+        let registry = fs::read_to_string(root.join("src/lib/days/mod.rs")).unwrap();
+        let days: Vec<u32> = registry
+            .lines()
+            .skip_while(|line| !line.starts_with("pub static DAYS"))
+            .take_while(|line| *line != "];")
+            .filter_map(|line| line.trim().strip_prefix("&day"))
+            .map(|entry| entry.split("::").next().unwrap().parse().unwrap())
+            .collect();
+        assert_eq!(days.last(), Some(&Scaffolded::DAY));
+        assert_eq!(registry_problem(&days), None, "{:?}", days);
+
+        let mut solutions = DAYS.to_vec();
+        solutions.push(&Scaffolded);
+        let day_dir = root.join(format!("src/bin/day{}", Scaffolded::DAY));
+        let failures = example_failures(&solutions, |day, n| match day {
+            Scaffolded::DAY => read_example(
+                &day_dir.join(format!("example{}.in", n)),
+                &day_dir.join(format!("example{}.answers", n)),
+                day,
+            ),
+            _ => read_example_for_day(day, n),
+        });
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+
+        let answers = Answers::load().unwrap();
+        let failures = answer_failures(&solutions, &answers, |day| match day {
+            Scaffolded::DAY => try_read_input_from_path(day_dir.join("input.in")),
+            _ => try_read_input_for_day(day),
+        });
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+
+        // reported as unchecked rather than as a failure
+        let (outcome, _) = run_part(&Scaffolded, Part::One, "input");
+        assert_eq!(outcome, Ok(Answer::Unsolved));
+
+        // the differential and generated input tests skip days without these
+        assert!(generator(Scaffolded::DAY).is_none());
+        assert!(oracle(Scaffolded::DAY).is_none());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+//! Differential testing: runs a solution and its [oracle](crate::oracle) on
+//! many [generated](crate::gen) inputs and shrinks the first disagreement to
+//! a minimal input that still shows it.
+
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use crate::{
+    cli::Part,
+    gen::generate,
+    oracle::{oracle, Oracle},
+    runner::run_part,
+    Answer, DynSolution,
+};
+
+/// An input on which a solution disagrees with the oracle.
+#[derive(Debug, Clone)]
+pub struct Mismatch {
+    pub day: u32,
+    pub part: Part,
+    /// The seed and size of the generated input the mismatch was found on.
+    pub seed: u64,
+    pub size: usize,
+    /// The smallest input found that still shows the mismatch.
+    pub input: String,
+    pub expected: Answer,
+    /// The solution's answer, or its panic.
+    pub actual: Result<Answer, String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(message) => message.clone(),
+        };
+        write!(
+            f,
+            "Day {} {} (seed {}, size {}): expected {}, got {}, on\n{}",
+            self.day, self.part, self.seed, self.size, self.expected, actual, self.input
+        )
+    }
+}
+
+/// How the solution's answer on an input compares with the oracle's.
+#[derive(Debug)]
+enum Comparison {
+    /// The oracle rejects the input, so there is nothing to compare with.
+    Rejected,
+    Agreed,
+    Disagreed {
+        expected: Answer,
+        /// The solution's answer, or its panic.
+        actual: Result<Answer, String>,
+    },
+}
+
+fn compare(solution: &dyn DynSolution, oracle: Oracle, part: Part, input: &str) -> Comparison {
+    let Some(expected) = oracle(part, input) else {
+        return Comparison::Rejected;
+    };
+    let (actual, _) = run_part(solution, part, input);
+    match &actual {
+        Ok(answer) if answer.to_string() == expected.to_string() => Comparison::Agreed,
+        _ => Comparison::Disagreed { expected, actual },
+    }
+}
+
+/// Checks the solution against its oracle on the inputs generated from
+/// `seeds`, each of a size between 1 and `max_size`. Returns how many inputs
+/// the oracle accepted for both parts and the solution agreed on, or the
+/// first mismatch, shrunk.
+pub fn check_day(
+    solution: &dyn DynSolution,
+    seeds: Range<u64>,
+    max_size: usize,
+) -> Result<usize, Mismatch> {
+    match oracle(solution.day()) {
+        Some(oracle) => check_against(solution, oracle, seeds, max_size),
+        None => Ok(0),
+    }
+}
+
+fn check_against(
+    solution: &dyn DynSolution,
+    oracle: Oracle,
+    seeds: Range<u64>,
+    max_size: usize,
+) -> Result<usize, Mismatch> {
+    let day = solution.day();
+    let mut checked = 0;
+    for seed in seeds {
+        let size = 1 + seed as usize % max_size.max(1);
+        let Some(input) = generate(day, seed, size) else {
+            return Ok(0);
+        };
+
+        let mut accepted = true;
+        for part in Part::ALL {
+            match compare(solution, oracle, part, &input) {
+                Comparison::Rejected => accepted = false,
+                Comparison::Agreed => {}
+                Comparison::Disagreed { expected, actual } => {
+                    let mismatch = Mismatch {
+                        day,
+                        part,
+                        seed,
+                        size,
+                        input,
+                        expected,
+                        actual,
+                    };
+                    return Err(shrink(solution, oracle, mismatch));
+                }
+            }
+        }
+        if accepted {
+            checked += 1;
+        }
+    }
+
+    Ok(checked)
+}
+
+/// Looks for a smaller input failing the same way (a wrong answer or a
+/// panic): first a smaller generated one, then by removing lines and, in
+/// grids, columns, until nothing can be removed anymore.
+fn shrink(solution: &dyn DynSolution, oracle: Oracle, mut mismatch: Mismatch) -> Mismatch {
+    let panicked = mismatch.actual.is_err();
+    let try_input = |mismatch: &mut Mismatch, input: String| -> bool {
+        if input.trim().is_empty() {
+            return false;
+        }
+        match compare(solution, oracle, mismatch.part, &input) {
+            Comparison::Disagreed { expected, actual } if actual.is_err() == panicked => {
+                mismatch.input = input;
+                mismatch.expected = expected;
+                mismatch.actual = actual;
+                true
+            }
+            _ => false,
+        }
+    };
+
+    for size in 1..mismatch.size {
+        if let Some(input) = generate(mismatch.day, mismatch.seed, size) {
+            if try_input(&mut mismatch, input) {
+                break;
+            }
+        }
+    }
+
+    'shrinking: loop {
+        let lines: Vec<&str> = mismatch.input.lines().collect();
+        let mut candidates = vec![];
+
+        let mut chunk = lines.len() / 2;
+        while chunk > 0 {
+            for start in (0..lines.len()).step_by(chunk) {
+                let end = (start + chunk).min(lines.len());
+                candidates.push([&lines[..start], &lines[end..]].concat().join("\n"));
+            }
+            chunk /= 2;
+        }
+
+        let width = lines[0].chars().count();
+        if width > 1 && lines.iter().all(|line| line.chars().count() == width) {
+            for column in 0..width {
+                let without_column = lines.iter().map(|line| {
+                    line.chars()
+                        .enumerate()
+                        .filter(|(idx, _)| *idx != column)
+                        .map(|(_, c)| c)
+                        .collect::<String>()
+                });
+                candidates.push(without_column.collect::<Vec<_>>().join("\n"));
+            }
+        }
+
+        for candidate in candidates {
+            if try_input(&mut mismatch, candidate) {
+                continue 'shrinking;
+            }
+        }
+        return mismatch;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_against, check_day, compare, Comparison};
+    use crate::{
+        cli::Part,
+        days::{self, DAYS},
+        gen::{generate, generator},
+        oracle::{self, oracle},
+        Answer, Solution,
+    };
+
+    #[test]
+    fn test_solutions_match_oracles() {
+        for solution in DAYS {
+            if generator(solution.day()).is_none() || oracle(solution.day()).is_none() {
+                continue;
+            }
+            match check_day(*solution, 0..1000, 30) {
+                Ok(checked) => assert!(checked >= 900, "Day {}: {}", solution.day(), checked),
+                Err(mismatch) => panic!("{}", mismatch),
+            }
+        }
+    }
+
+    #[test]
+    fn test_day10_programs_of_any_length() {
+        let day10 = days::get(10).unwrap();
+        // shorter than the first signal, around a full screen and past it
+        for size in [1, 19, 20, 239, 240, 241, 1000] {
+            for seed in 0..10 {
+                let input = generate(10, seed, size).unwrap();
+                for part in Part::ALL {
+                    match compare(day10, oracle::day10, part, &input) {
+                        Comparison::Agreed => {}
+                        comparison => panic!(
+                            "Day 10 {} (seed {}, size {}): {:?}",
+                            part, seed, size, comparison
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejected_inputs_are_not_counted() {
+        let day4 = days::get(4).unwrap();
+        assert_eq!(check_against(day4, oracle::day4, 0..10, 5).unwrap(), 10);
+        assert_eq!(check_against(day4, |_, _| None, 0..10, 5).unwrap(), 0);
+
+        let part_one_only = |part, input: &str| match part {
+            Part::One => oracle::day4(part, input),
+            Part::Two => None,
+        };
+        assert_eq!(check_against(day4, part_one_only, 0..10, 5).unwrap(), 0);
+    }
+
+    /// Day 4 counting only the pairs whose first range contains the second.
+    struct OneSided;
+
+    impl Solution for OneSided {
+        const DAY: u32 = 4;
+
+        type Input = Vec<[u32; 4]>;
+
+        fn parse(&self, input: &str) -> Self::Input {
+            input
+                .lines()
+                .map(|line| {
+                    let numbers: Vec<u32> = line
+                        .split([',', '-'])
+                        .map(|number| number.parse().unwrap())
+                        .collect();
+                    [numbers[0], numbers[1], numbers[2], numbers[3]]
+                })
+                .collect()
+        }
+
+        fn part_one(&self, pairs: &Self::Input) -> Answer {
+            pairs
+                .iter()
+                .filter(|[a, b, c, d]| a <= c && d <= b)
+                .count()
+                .into()
+        }
+
+        fn part_two(&self, pairs: &Self::Input) -> Answer {
+            pairs
+                .iter()
+                .filter(|[a, b, c, d]| a <= d && c <= b)
+                .count()
+                .into()
+        }
+    }
+
+    #[test]
+    fn test_mismatch_is_shrunk() {
+        let mismatch = check_day(&OneSided, 0..100, 50).unwrap_err();
+        assert_eq!(mismatch.day, 4);
+        assert_eq!(mismatch.input.lines().count(), 1, "{}", mismatch);
+        assert_eq!(mismatch.expected.to_string(), "1");
+        assert_eq!(mismatch.actual.unwrap().to_string(), "0");
+    }
+}
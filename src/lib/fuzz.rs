@@ -0,0 +1,128 @@
+//! Fuzzing entry points: each feeds arbitrary bytes to a day's parser, which
+//! must return an error on malformed input rather than panic. They are run
+//! by the targets in `fuzz/` with `cargo fuzz`, and on mutated generated
+//! inputs by `cargo test`.
+
+use std::borrow::Cow;
+
+use crate::{
+    days::{day10, day2, day4, day5, day7, day8, day9},
+    Solution,
+};
+
+pub type Target = fn(&[u8]);
+
+/// Every fuzz target, by name.
+pub const TARGETS: &[(&str, Target)] = &[
+    ("day2", day2),
+    ("day4", day4),
+    ("day5", day5),
+    ("day7", day7),
+    ("day8", day8),
+    ("day9", day9),
+    ("day10", day10),
+];
+
+fn text(data: &[u8]) -> Cow<'_, str> {
+    String::from_utf8_lossy(data)
+}
+
+pub fn day2(data: &[u8]) {
+    for letter in text(data).chars() {
+        let _ = day2::Shape::from_char(letter);
+        let _ = day2::RoundResult::from_char(letter);
+    }
+    let _ = day2::parse_input(&text(data));
+}
+
+pub fn day4(data: &[u8]) {
+    let text = text(data);
+    for section in text.split([',', '\n']) {
+        let _ = day4::section_to_assignment(section);
+    }
+    let _ = day4::get_assignments(text.lines()).collect::<Result<Vec<_>, _>>();
+}
+
+/// Also solves what parses: moves the parser accepts must be possible.
+pub fn day5(data: &[u8]) {
+    if let Ok(input) = day5::input_to_domain(&text(data)) {
+        day5::Day5.part_one(&input);
+        day5::Day5.part_two(&input);
+    }
+}
+
+pub fn day7(data: &[u8]) {
+    let _ = day7::parse_input(&text(data));
+}
+
+pub fn day8(data: &[u8]) {
+    let _ = day8::parse_input(&text(data));
+}
+
+pub fn day9(data: &[u8]) {
+    let _ = day9::parse_input(text(data).lines()).collect::<Result<Vec<_>, _>>();
+}
+
+pub fn day10(data: &[u8]) {
+    let _ = day10::parse_input(text(data).lines()).collect::<Result<Vec<_>, _>>();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::TARGETS;
+    use crate::{
+        gen::{generate, Rng},
+        runner::panic_message,
+    };
+
+    /// Random bytes, and generated inputs with random bytes replaced,
+    /// inserted and removed.
+    fn fuzz_inputs(day: u32) -> Vec<Vec<u8>> {
+        let mut rng = Rng::new(day as u64);
+        let mut inputs = vec![vec![], b"\n\n\n".to_vec()];
+
+        for seed in 0..200 {
+            let length = rng.range(0..=64);
+            inputs.push((0..length).map(|_| rng.next_u64() as u8).collect());
+
+            let mut input = generate(day, seed, 1 + seed as usize % 8)
+                .unwrap()
+                .into_bytes();
+            for _ in 0..rng.range(1..=4) {
+                let idx = rng.range(0..=input.len() - 1);
+                match rng.range(0..=2) {
+                    0 => input[idx] = *rng.choose(b" \n-,$0123456789azAZ"),
+                    1 => input.insert(idx, rng.next_u64() as u8),
+                    _ => {
+                        input.remove(idx);
+                    }
+                }
+                if input.is_empty() {
+                    break;
+                }
+            }
+            inputs.push(input);
+        }
+
+        inputs
+    }
+
+    #[test]
+    fn test_parsers_never_panic() {
+        for (name, target) in TARGETS {
+            let day = name["day".len()..].parse().unwrap();
+            for input in fuzz_inputs(day) {
+                if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| target(&input))) {
+                    panic!(
+                        "{} panicked ({}) on {:?}",
+                        name,
+                        panic_message(payload.as_ref()),
+                        String::from_utf8_lossy(&input)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,431 @@
+//! Seeded generators of valid puzzle inputs, for benchmarking and stress
+//! testing the solutions on more than the one `input.in`.
+//!
+//! The same seed and size always give the same input.
+
+use std::ops::RangeInclusive;
+
+/// Small deterministic random number generator (SplitMix64), so inputs can
+/// be generated without external crates.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly picks a number from a non-empty range.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let span = (end - start) as u64 + 1;
+        start + (self.next_u64() % span) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0..=idx));
+        }
+    }
+}
+
+/// Generates an input of roughly `size` elements (elves, rounds, lines,
+/// directories...; see the generator of each day).
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+pub fn generator(day: u32) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day1,
+        2 => day2,
+        3 => day3,
+        4 => day4,
+        5 => day5,
+        6 => day6,
+        7 => day7,
+        8 => day8,
+        9 => day9,
+        10 => day10,
+        _ => return None,
+    };
+    Some(generator)
+}
+
+pub fn generate(day: u32, seed: u64, size: usize) -> Option<String> {
+    generator(day).map(|generator| generator(&mut Rng::new(seed), size))
+}
+
+// day1 ------------------------------------------------------------
+
+/// Calories of `size` elves (at least 3), separated by empty lines.
+pub fn day1(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            (0..rng.range(1..=6))
+                .map(|_| rng.range(1000..=60000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+// day2 ------------------------------------------------------------
+
+/// A strategy guide of `size` rounds.
+pub fn day2(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// day3 ------------------------------------------------------------
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Rucksacks in groups of three, `size` rounded up to a multiple of three.
+/// Both compartments of a rucksack share exactly one item type and the
+/// rucksacks of a group share exactly one badge.
+pub fn day3(rng: &mut Rng, size: usize) -> String {
+    let mut rucksacks = vec![];
+
+    for _ in 0..size.max(1).div_ceil(3) {
+        let mut items: Vec<char> = ITEMS.chars().collect();
+        rng.shuffle(&mut items);
+        let badge = items.pop().unwrap();
+
+        // every elf of the group gets its own items, plus the badge
+        for pool in items.chunks(items.len() / 3).take(3) {
+            let mut pool = pool.to_vec();
+            pool.push(badge);
+            rng.shuffle(&mut pool);
+            let shared = pool.pop().unwrap();
+
+            let (left, right) = pool.split_at(pool.len() / 2);
+            let half_len = rng.range(2..=12);
+            let mut halves = [vec![shared], vec![shared]];
+            for (half, only_in_half) in halves.iter_mut().zip([left, right]) {
+                if only_in_half.contains(&badge) {
+                    half.push(badge);
+                }
+                while half.len() < half_len {
+                    half.push(*rng.choose(only_in_half));
+                }
+                rng.shuffle(half);
+            }
+
+            rucksacks.push(halves.concat().into_iter().collect::<String>());
+        }
+    }
+
+    rucksacks.join("\n")
+}
+
+// day4 ------------------------------------------------------------
+
+/// `size` pairs of section assignments.
+pub fn day4(rng: &mut Rng, size: usize) -> String {
+    let mut assignment = || {
+        let start = rng.range(1..=99);
+        format!("{}-{}", start, rng.range(start..=99))
+    };
+
+    (0..size.max(1))
+        .map(|_| format!("{},{}", assignment(), assignment()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// day5 ------------------------------------------------------------
+
+/// A diagram of 2 to 9 stacks followed by `size` moves. Moves never empty a
+/// stack, so every stack has a top crate at the end.
+pub fn day5(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<char> = ITEMS[26..].chars().collect();
+    let mut heights: Vec<usize> = (0..rng.range(2..=9)).map(|_| rng.range(2..=8)).collect();
+    let max_height = *heights.iter().max().unwrap();
+
+    let mut diagram = vec![];
+    for row in (0..max_height).rev() {
+        let cells: Vec<String> = heights
+            .iter()
+            .map(|height| match *height > row {
+                true => format!("[{}]", rng.choose(&labels)),
+                false => "   ".to_string(),
+            })
+            .collect();
+        diagram.push(cells.join(" "));
+    }
+    let numbers: Vec<String> = (1..=heights.len()).map(|n| format!(" {} ", n)).collect();
+    diagram.push(numbers.join(" "));
+
+    let mut moves = vec![];
+    while moves.len() < size.max(1) {
+        let from = rng.range(0..=heights.len() - 1);
+        let to = rng.range(0..=heights.len() - 1);
+        if from == to || heights[from] < 2 {
+            continue;
+        }
+
+        let quantity = rng.range(1..=heights[from] - 1);
+        heights[from] -= quantity;
+        heights[to] += quantity;
+        moves.push(format!("move {} from {} to {}", quantity, from + 1, to + 1));
+    }
+
+    format!("{}\n\n{}", diagram.join("\n"), moves.join("\n"))
+}
+
+// day6 ------------------------------------------------------------
+
+/// A datastream of about `size` characters with a start-of-message marker
+/// (and so a start-of-packet marker) somewhere in it.
+pub fn day6(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let marker_at = rng.range(0..=size - 14);
+    let letters: Vec<char> = ITEMS[..26].chars().collect();
+
+    // three letters can't form a marker of four different ones
+    let mut stream: Vec<char> = (0..marker_at).map(|_| *rng.choose(&letters[..3])).collect();
+    let mut marker = letters.clone();
+    rng.shuffle(&mut marker);
+    stream.extend(&marker[..14]);
+    stream.extend((stream.len()..size).map(|_| *rng.choose(&letters)));
+
+    stream.into_iter().collect()
+}
+
+// day7 ------------------------------------------------------------
+
+struct GenDir {
+    files: Vec<(String, usize)>,
+    dirs: Vec<(String, GenDir)>,
+}
+
+/// A terminal transcript exploring a file system of `size` directories
+/// (besides the root), depth first. Like real transcripts, it may stop
+/// deep in the tree instead of returning to the root. The total size is
+/// between the 40M and 70M the puzzle needs.
+pub fn day7(rng: &mut Rng, size: usize) -> String {
+    let mut files = 0;
+    let mut root = gen_dir(rng, size, &mut files);
+
+    let max_file = (50_000_000 / files.max(1)).clamp(1, 300_000);
+    let mut total = 0;
+    assign_sizes(rng, &mut root, max_file, &mut total);
+    let target = rng.range(40_000_001..=69_999_999);
+    if total < target {
+        root.files.push(("padding.dat".to_string(), target - total));
+    }
+
+    let mut transcript = vec!["$ cd /".to_string()];
+    write_dir(&root, &mut transcript);
+    if rng.chance(0.5) {
+        while transcript.last().is_some_and(|line| line == "$ cd ..") {
+            transcript.pop();
+        }
+    }
+    transcript.join("\n")
+}
+
+/// A directory containing `dirs` directories in total, nested randomly.
+fn gen_dir(rng: &mut Rng, dirs: usize, files: &mut usize) -> GenDir {
+    let mut dir = GenDir {
+        files: vec![],
+        dirs: vec![],
+    };
+
+    for idx in 0..rng.range(0..=4) {
+        let extension = rng.choose(&["", ".txt", ".dat", ".log"]);
+        dir.files.push((format!("f{}{}", idx, extension), 0));
+    }
+    *files += dir.files.len();
+
+    let mut remaining = dirs;
+    while remaining > 0 {
+        let nested = rng.range(0..=remaining.min(dirs / 2 + 1) - 1);
+        let name = format!("d{}", dir.dirs.len());
+        dir.dirs.push((name, gen_dir(rng, nested, files)));
+        remaining -= nested + 1;
+    }
+
+    dir
+}
+
+fn assign_sizes(rng: &mut Rng, dir: &mut GenDir, max_file: usize, total: &mut usize) {
+    for (_, size) in dir.files.iter_mut() {
+        *size = rng.range(1..=max_file);
+        *total += *size;
+    }
+    for (_, child) in dir.dirs.iter_mut() {
+        assign_sizes(rng, child, max_file, total);
+    }
+}
+
+fn write_dir(dir: &GenDir, transcript: &mut Vec<String>) {
+    transcript.push("$ ls".to_string());
+    for (name, _) in &dir.dirs {
+        transcript.push(format!("dir {}", name));
+    }
+    for (name, size) in &dir.files {
+        transcript.push(format!("{} {}", size, name));
+    }
+
+    for (name, child) in &dir.dirs {
+        transcript.push(format!("$ cd {}", name));
+        write_dir(child, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+// day8 ------------------------------------------------------------
+
+/// A square grid of `size` by `size` trees.
+pub fn day8(rng: &mut Rng, size: usize) -> String {
+    tree_grid(rng, size.max(1), size.max(1))
+}
+
+pub fn tree_grid(rng: &mut Rng, width: usize, height: usize) -> String {
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| char::from(b'0' + rng.range(0..=9) as u8))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// day9 ------------------------------------------------------------
+
+/// `size` motions of the rope's head.
+pub fn day9(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&["R", "U", "L", "D"]),
+                rng.range(1..=20)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// day10 -----------------------------------------------------------
+
+/// A program running for `size` cycles (at least 1). The screen only has
+/// pixels for the first 240, and the sprite always stays within its columns.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let cycles = size.max(1);
+    let mut program = vec![];
+    let mut x_register: i64 = 1;
+    let mut cycle = 0;
+
+    while cycle < cycles {
+        if cycle + 2 > cycles || rng.chance(0.3) {
+            program.push("noop".to_string());
+            cycle += 1;
+            continue;
+        }
+
+        let value = rng.range(2..=38) as i64 - x_register;
+        x_register += value;
+        program.push(format!("addx {}", value));
+        cycle += 2;
+    }
+
+    program.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{day3, day5, generate, generator, Rng};
+    use crate::{cli::Part, days::DAYS};
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let numbers: Vec<usize> = (0..100).map(|_| a.range(3..=5)).collect();
+        assert_eq!(
+            numbers,
+            (0..100).map(|_| b.range(3..=5)).collect::<Vec<_>>()
+        );
+        assert!(numbers.iter().all(|n| (3..=5).contains(n)));
+        assert_eq!(generate(8, 1, 10), generate(8, 1, 10));
+        assert_ne!(generate(8, 1, 10), generate(8, 2, 10));
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for solution in DAYS {
+            if generator(solution.day()).is_none() {
+                continue;
+            }
+            for seed in 0..20 {
+                for size in [1, 10, 100] {
+                    let input = generate(solution.day(), seed, size).unwrap();
+                    for part in Part::ALL {
+                        solution.run(part, &input);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_day3_shares_one_item() {
+        let input = day3(&mut Rng::new(3), 30);
+        let rucksacks: Vec<HashSet<char>> = input.lines().map(|r| r.chars().collect()).collect();
+        assert_eq!(rucksacks.len(), 30);
+
+        for rucksack in input.lines() {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            let left: HashSet<char> = left.chars().collect();
+            let right: HashSet<char> = right.chars().collect();
+            assert_eq!(left.intersection(&right).count(), 1, "{}", rucksack);
+        }
+        for group in rucksacks.chunks(3) {
+            let common: Vec<&char> = group[0]
+                .iter()
+                .filter(|item| group[1].contains(item) && group[2].contains(item))
+                .collect();
+            assert_eq!(common.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_day5_moves_are_legal() {
+        let input = day5(&mut Rng::new(5), 50);
+        let (diagram, moves) = input.split_once("\n\n").unwrap();
+        let stacks = diagram.lines().last().unwrap().split_whitespace().count();
+        assert!((2..=9).contains(&stacks));
+        assert_eq!(moves.lines().count(), 50);
+    }
+}
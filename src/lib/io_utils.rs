@@ -1,13 +1,667 @@
-use std::{fmt::Debug, fs, path::Path};
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    mem,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a directory laid out like `src/bin`,
+/// i.e. containing `day{N}/input.in` files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Everything that can go wrong while loading a puzzle input.
+///
+/// Each variant carries the path that was read and, when the input was
+/// requested through [`try_read_input_for_day`], the day it belongs to.
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        path: PathBuf,
+        day: Option<u32>,
+    },
+    PermissionDenied {
+        path: PathBuf,
+        day: Option<u32>,
+    },
+    InvalidUtf8 {
+        path: PathBuf,
+        day: Option<u32>,
+    },
+    Empty {
+        path: PathBuf,
+        day: Option<u32>,
+    },
+    /// A line of an answers file that isn't a `part_one = ...` or
+    /// `part_two = ...` entry.
+    Malformed {
+        path: PathBuf,
+        day: Option<u32>,
+        line: usize,
+    },
+    /// None of the candidate locations for a day's file exist.
+    Unresolved {
+        day: u32,
+        tried: Vec<PathBuf>,
+    },
+    Io {
+        path: PathBuf,
+        day: Option<u32>,
+        source: io::Error,
+    },
+}
+
+impl InputError {
+    /// The path that was read, or `None` if no candidate path existed.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            InputError::NotFound { path, .. }
+            | InputError::PermissionDenied { path, .. }
+            | InputError::InvalidUtf8 { path, .. }
+            | InputError::Empty { path, .. }
+            | InputError::Malformed { path, .. }
+            | InputError::Io { path, .. } => Some(path),
+            InputError::Unresolved { .. } => None,
+        }
+    }
+
+    pub fn day(&self) -> Option<u32> {
+        match self {
+            InputError::NotFound { day, .. }
+            | InputError::PermissionDenied { day, .. }
+            | InputError::InvalidUtf8 { day, .. }
+            | InputError::Empty { day, .. }
+            | InputError::Malformed { day, .. }
+            | InputError::Io { day, .. } => *day,
+            InputError::Unresolved { day, .. } => Some(*day),
+        }
+    }
+
+    fn from_io(err: io::Error, path: PathBuf, day: Option<u32>) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => InputError::NotFound { path, day },
+            io::ErrorKind::PermissionDenied => InputError::PermissionDenied { path, day },
+            io::ErrorKind::InvalidData => InputError::InvalidUtf8 { path, day },
+            _ => InputError::Io {
+                path,
+                day,
+                source: err,
+            },
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day() {
+            write!(f, "day {}: ", day)?;
+        }
+        match self {
+            InputError::NotFound { path, .. } => {
+                write!(f, "input file not found: {}", path.display())
+            }
+            InputError::PermissionDenied { path, .. } => {
+                write!(
+                    f,
+                    "permission denied while reading input: {}",
+                    path.display()
+                )
+            }
+            InputError::InvalidUtf8 { path, .. } => {
+                write!(f, "input is not valid UTF-8: {}", path.display())
+            }
+            InputError::Empty { path, .. } => write!(f, "input file is empty: {}", path.display()),
+            InputError::Malformed { path, line, .. } => {
+                write!(f, "malformed answers file {}:{}", path.display(), line)
+            }
+            InputError::Unresolved { tried, .. } => {
+                write!(f, "input file not found, tried:")?;
+                tried
+                    .iter()
+                    .try_for_each(|path| write!(f, "\n  {}", path.display()))
+            }
+            InputError::Io { path, source, .. } => {
+                write!(
+                    f,
+                    "could not read input from {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Path reported in errors for input read from stdin.
+pub const STDIN_PATH: &str = "<stdin>";
+
+fn read_input(path: &Path, day: Option<u32>) -> Result<String, InputError> {
+    let bytes = fs::read(path).map_err(|err| InputError::from_io(err, path.into(), day))?;
+    decode_input(bytes, path, day)
+}
+
+fn decode_input(bytes: Vec<u8>, path: &Path, day: Option<u32>) -> Result<String, InputError> {
+    let input = String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8 {
+        path: path.into(),
+        day,
+    })?;
+
+    let input = normalize_input(&input);
+    if input.trim().is_empty() {
+        return Err(InputError::Empty {
+            path: path.into(),
+            day,
+        });
+    }
+
+    Ok(input)
+}
+
+// normalization ---------------------------------------------------
+
+const BOM: char = '\u{feff}';
+
+/// Undoes what editors and browsers do to copied inputs: strips a UTF-8 BOM,
+/// converts CRLF line endings to LF and removes the newlines at the end of
+/// the input, so it never ends with one. Other trailing whitespace, like the
+/// spaces ending a diagram row, is kept.
+///
+/// Every `try_read_*` function returns normalized input, and [`InputLines`]
+/// yields its lines.
+pub fn normalize_input(input: &str) -> String {
+    input
+        .strip_prefix(BOM)
+        .unwrap_or(input)
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// Right-pads every row with spaces to the width of the longest one, for
+/// ASCII diagrams whose trailing spaces got stripped.
+pub fn pad_rows<S: AsRef<str>>(rows: &[S]) -> Vec<String> {
+    let width = rows
+        .iter()
+        .map(|row| row.as_ref().chars().count())
+        .max()
+        .unwrap_or(0);
+
+    rows.iter()
+        .map(|row| format!("{:<width$}", row.as_ref(), width = width))
+        .collect()
+}
+
+/// Checksum of an input (64-bit FNV-1a as 16 hex digits), to tell which
+/// input produced an answer without publishing the input itself.
+pub fn input_checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn try_read_input_from_path<P>(path: P) -> Result<String, InputError>
+where
+    P: AsRef<Path>,
+{
+    read_input(path.as_ref(), None)
+}
+
+pub fn try_read_input_from_stdin() -> Result<String, InputError> {
+    let path = Path::new(STDIN_PATH);
+    let mut bytes = vec![];
+    io::stdin()
+        .lock()
+        .read_to_end(&mut bytes)
+        .map_err(|err| InputError::from_io(err, path.into(), None))?;
+
+    decode_input(bytes, path, None)
+}
+
+/// Locations where `file_name` for the given day is looked up, in order:
+/// the `override_dir` (normally taken from [`INPUT_DIR_ENV`]), the crate's
+/// manifest directory and finally a path relative to the working directory.
+pub fn candidate_paths(day: u32, file_name: &str, override_dir: Option<&Path>) -> Vec<PathBuf> {
+    let day_dir = format!("day{}", day);
+    let mut candidates = vec![];
+
+    if let Some(dir) = override_dir {
+        candidates.push(dir.join(&day_dir).join(file_name));
+    }
+    candidates.push(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/bin")
+            .join(&day_dir)
+            .join(file_name),
+    );
+    candidates.push(Path::new("src/bin").join(&day_dir).join(file_name));
+
+    candidates
+}
+
+/// Directory new day files are written to: the [`INPUT_DIR_ENV`] override
+/// if set, otherwise the crate's `src/bin`.
+pub fn default_input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bin"))
+}
+
+/// Finds the first existing location of `file_name` for the given day.
+pub fn resolve_day_file(day: u32, file_name: &str) -> Result<PathBuf, InputError> {
+    let override_dir = env::var_os(INPUT_DIR_ENV).map(PathBuf::from);
+    let tried = candidate_paths(day, file_name, override_dir.as_deref());
+
+    match tried.iter().find(|path| path.exists()) {
+        Some(path) => Ok(path.to_owned()),
+        None => Err(InputError::Unresolved { day, tried }),
+    }
+}
+
+pub fn try_read_input_for_day(day: u32) -> Result<String, InputError> {
+    let path = resolve_day_file(day, "input.in")?;
+    read_input(&path, Some(day))
+}
 
 pub fn read_input_from_path<P>(path: P) -> String
 where
-    P: AsRef<Path> + Debug + Copy,
+    P: AsRef<Path>,
 {
-    fs::read_to_string(path)
-        .unwrap_or_else(|_| panic!("Could not read input from path: {:?}", path))
+    try_read_input_from_path(path).unwrap_or_else(|err| panic!("{}", err))
 }
 
 pub fn read_input_for_day(day: u32) -> String {
-    read_input_from_path(format!("src/bin/day{}/input.in", day).as_str())
+    try_read_input_for_day(day).unwrap_or_else(|err| panic!("{}", err))
+}
+
+// examples --------------------------------------------------------
+
+/// One of the example inputs from a puzzle's description, stored as
+/// `src/bin/dayN/example{n}.in`, together with the answers published for it
+/// in the `example{n}.answers` sidecar. Parts without a published answer
+/// are `None`.
+#[derive(Debug)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+fn parse_answers(content: &str, path: &Path, day: u32) -> Result<Example, InputError> {
+    let mut example = Example {
+        input: String::new(),
+        part_one: None,
+        part_two: None,
+    };
+
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let malformed = || InputError::Malformed {
+            path: path.into(),
+            day: Some(day),
+            line: idx + 1,
+        };
+        let (key, value) = line.split_once('=').ok_or_else(malformed)?;
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "part_one" => example.part_one = value,
+            "part_two" => example.part_two = value,
+            _ => return Err(malformed()),
+        }
+    }
+
+    Ok(example)
+}
+
+pub fn read_example_for_day(day: u32, n: u32) -> Result<Example, InputError> {
+    let input_path = resolve_day_file(day, &format!("example{}.in", n))?;
+    let answers_path = resolve_day_file(day, &format!("example{}.answers", n))?;
+    read_example(&input_path, &answers_path, day)
+}
+
+/// Reads an example from its input and answers files.
+pub fn read_example(
+    input_path: &Path,
+    answers_path: &Path,
+    day: u32,
+) -> Result<Example, InputError> {
+    let answers = read_input(answers_path, Some(day))?;
+    let input = read_input(input_path, Some(day))?;
+
+    Ok(Example {
+        input,
+        ..parse_answers(&answers, answers_path, day)?
+    })
+}
+
+// streaming -------------------------------------------------------
+
+/// Buffered iterator over the lines of an input, for inputs too large to be
+/// loaded into a single `String`. Yields the same lines as the
+/// [normalized](normalize_input) input: line endings (`\n` or `\r\n`), a
+/// leading BOM and the empty lines at the end are stripped.
+pub struct InputLines {
+    reader: Box<dyn BufRead>,
+    path: PathBuf,
+    day: Option<u32>,
+    buf: Vec<u8>,
+    first_line: bool,
+    /// empty lines read but held back until a non-empty line follows them
+    empty_lines: usize,
+    after_empty_lines: Option<Result<String, InputError>>,
+}
+
+impl InputLines {
+    fn new(reader: Box<dyn BufRead>, path: PathBuf, day: Option<u32>) -> Self {
+        Self {
+            reader,
+            path,
+            day,
+            buf: vec![],
+            first_line: true,
+            empty_lines: 0,
+            after_empty_lines: None,
+        }
+    }
+
+    fn read_line(&mut self) -> Option<Result<String, InputError>> {
+        self.buf.clear();
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                if self.buf.ends_with(b"\n") {
+                    self.buf.pop();
+                    if self.buf.ends_with(b"\r") {
+                        self.buf.pop();
+                    }
+                }
+                if self.first_line
+                    && self
+                        .buf
+                        .starts_with(BOM.encode_utf8(&mut [0; 3]).as_bytes())
+                {
+                    self.buf.drain(..BOM.len_utf8());
+                }
+                self.first_line = false;
+
+                let line = String::from_utf8(mem::take(&mut self.buf)).map_err(|_| {
+                    InputError::InvalidUtf8 {
+                        path: self.path.clone(),
+                        day: self.day,
+                    }
+                });
+                Some(line)
+            }
+            Err(err) => Some(Err(InputError::from_io(err, self.path.clone(), self.day))),
+        }
+    }
+}
+
+impl Iterator for InputLines {
+    type Item = Result<String, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.after_empty_lines.is_some() {
+                if self.empty_lines > 0 {
+                    self.empty_lines -= 1;
+                    return Some(Ok(String::new()));
+                }
+                return self.after_empty_lines.take();
+            }
+
+            match self.read_line()? {
+                Ok(line) if line.is_empty() => self.empty_lines += 1,
+                line => self.after_empty_lines = Some(line),
+            }
+        }
+    }
+}
+
+/// Iterator over fixed-size byte chunks of an input, for stream-like puzzles
+/// that don't care about lines. The last chunk may be shorter.
+pub struct InputChunks {
+    reader: Box<dyn Read>,
+    path: PathBuf,
+    day: Option<u32>,
+    chunk_size: usize,
+}
+
+impl Iterator for InputChunks {
+    type Item = Result<Vec<u8>, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = vec![];
+        match self
+            .reader
+            .by_ref()
+            .take(self.chunk_size as u64)
+            .read_to_end(&mut chunk)
+        {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(chunk)),
+            Err(err) => Some(Err(InputError::from_io(err, self.path.clone(), self.day))),
+        }
+    }
+}
+
+fn open_file(path: &Path, day: Option<u32>) -> Result<File, InputError> {
+    File::open(path).map_err(|err| InputError::from_io(err, path.into(), day))
+}
+
+pub fn try_lines_from_path<P>(path: P) -> Result<InputLines, InputError>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file = open_file(path, None)?;
+    Ok(InputLines::new(
+        Box::new(BufReader::new(file)),
+        path.into(),
+        None,
+    ))
+}
+
+pub fn try_lines_for_day(day: u32) -> Result<InputLines, InputError> {
+    let path = resolve_day_file(day, "input.in")?;
+    let file = open_file(&path, Some(day))?;
+    Ok(InputLines::new(
+        Box::new(BufReader::new(file)),
+        path,
+        Some(day),
+    ))
+}
+
+pub fn lines_from_stdin() -> InputLines {
+    InputLines::new(
+        Box::new(BufReader::new(io::stdin())),
+        STDIN_PATH.into(),
+        None,
+    )
+}
+
+pub fn try_chunks_from_path<P>(path: P, chunk_size: usize) -> Result<InputChunks, InputError>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let file = open_file(path, None)?;
+    Ok(InputChunks {
+        reader: Box::new(BufReader::new(file)),
+        path: path.into(),
+        day: None,
+        chunk_size,
+    })
+}
+
+pub fn chunks_from_stdin(chunk_size: usize) -> InputChunks {
+    InputChunks {
+        reader: Box::new(io::stdin()),
+        path: STDIN_PATH.into(),
+        day: None,
+        chunk_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use super::{
+        candidate_paths, input_checksum, normalize_input, pad_rows, read_example_for_day,
+        read_input_for_day, try_chunks_from_path, try_lines_for_day, try_lines_from_path,
+        try_read_input_for_day, try_read_input_from_path, InputError,
+    };
+
+    #[test]
+    fn test_reading_existing_day() {
+        let input = try_read_input_for_day(1).unwrap();
+        assert!(!input.is_empty());
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let err = try_read_input_for_day(99).unwrap_err();
+        match &err {
+            InputError::Unresolved { day, tried } => {
+                assert_eq!(*day, 99);
+                assert!(tried.len() >= 2, "Both fallback locations should be tried");
+            }
+            other => panic!("Expected an unresolved input, got {:?}", other),
+        }
+        assert!(err.to_string().contains("src/bin/day99/input.in"));
+    }
+
+    #[test]
+    fn test_candidate_paths_order() {
+        let candidates = candidate_paths(3, "input.in", Some(Path::new("/inputs")));
+
+        assert_eq!(candidates[0], Path::new("/inputs/day3/input.in"));
+        assert!(candidates[1].is_absolute());
+        assert!(candidates[1].ends_with("src/bin/day3/input.in"));
+        assert_eq!(candidates[2], Path::new("src/bin/day3/input.in"));
+        assert_eq!(candidate_paths(3, "input.in", None).len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_utf8_and_empty_inputs() {
+        let dir = env::temp_dir().join(format!("aoc_io_utils_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let invalid = dir.join("invalid.in");
+        fs::write(&invalid, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(matches!(
+            try_read_input_from_path(&invalid),
+            Err(InputError::InvalidUtf8 { day: None, .. })
+        ));
+
+        let empty = dir.join("empty.in");
+        fs::write(&empty, "\n").unwrap();
+        assert!(matches!(
+            try_read_input_from_path(&empty),
+            Err(InputError::Empty { day: None, .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_streaming_lines_match_read_input() {
+        let streamed: Vec<String> = try_lines_for_day(4).unwrap().map(Result::unwrap).collect();
+        let read: Vec<String> = read_input_for_day(4).lines().map(String::from).collect();
+
+        assert_eq!(streamed, read);
+    }
+
+    #[test]
+    fn test_streaming_lines_and_chunks() {
+        let dir = env::temp_dir().join(format!("aoc_io_utils_stream_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("stream.in");
+        fs::write(&path, "\u{feff}noop\r\naddx 3\n\nlast").unwrap();
+
+        let lines: Vec<String> = try_lines_from_path(&path)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["noop", "addx 3", "", "last"]);
+
+        let chunks: Vec<Vec<u8>> = try_chunks_from_path(&path, 8)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), fs::read(&path).unwrap());
+
+        // streaming yields the lines of the normalized input
+        for raw in [
+            "\u{feff}a\r\n\r\nb \n\n\n",
+            "\n\na\n",
+            " 1   2 \n",
+            "a\n\n \n\n",
+        ] {
+            fs::write(&path, raw).unwrap();
+            let streamed: Vec<String> = try_lines_from_path(&path)
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+            let normalized = normalize_input(raw);
+            assert_eq!(
+                streamed,
+                normalized.lines().collect::<Vec<_>>(),
+                "{:?}",
+                raw
+            );
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reading_examples() {
+        let example = read_example_for_day(9, 2).unwrap();
+        assert!(example.input.starts_with("R 5"));
+        assert_eq!(example.part_one, None);
+        assert_eq!(example.part_two.as_deref(), Some("36"));
+
+        let err = read_example_for_day(9, 99).unwrap_err();
+        assert!(matches!(err, InputError::Unresolved { day: 9, .. }));
+        assert!(err.to_string().contains("example99.in"));
+    }
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(
+            normalize_input("\u{feff}noop\r\naddx 3\r\n\r\n"),
+            "noop\naddx 3"
+        );
+        assert_eq!(normalize_input("    [D]\n[N] [C]\n"), "    [D]\n[N] [C]");
+        assert_eq!(normalize_input(" 1   2 \r\n\n"), " 1   2 ");
+        assert_eq!(
+            pad_rows(&["    [D]", "[N] [C]    ", " 1"]),
+            vec!["    [D]    ", "[N] [C]    ", " 1         "]
+        );
+    }
+
+    #[test]
+    fn test_input_checksum() {
+        assert_eq!(input_checksum(""), "cbf29ce484222325");
+        assert_eq!(input_checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_checksum("1\n2"), input_checksum("2\n1"));
+    }
 }
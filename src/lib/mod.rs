@@ -1,2 +1,20 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod fuzz;
+pub mod gen;
 pub mod io_utils;
+pub mod oracle;
+pub mod parse;
+pub mod profiles;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
 pub mod tree;
+pub mod watch;
+
+pub use solution::{Answer, DynSolution, Solution};
@@ -0,0 +1,444 @@
+//! Slow but obviously correct reference solutions ("oracles"), written
+//! straight from the puzzle descriptions, to check the real solutions
+//! against on generated inputs (see [`crate::differential`]).
+//!
+//! Oracles parse strictly and return `None` for inputs the puzzle doesn't
+//! allow, instead of guessing what they mean.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{cli::Part, Answer};
+
+pub type Oracle = fn(Part, &str) -> Option<Answer>;
+
+pub fn oracle(day: u32) -> Option<Oracle> {
+    let oracle: Oracle = match day {
+        1 => day1,
+        2 => day2,
+        3 => day3,
+        4 => day4,
+        5 => day5,
+        6 => day6,
+        7 => day7,
+        8 => day8,
+        9 => day9,
+        10 => day10,
+        _ => return None,
+    };
+    Some(oracle)
+}
+
+// day1 ------------------------------------------------------------
+
+pub fn day1(part: Part, input: &str) -> Option<Answer> {
+    let mut elves = vec![];
+    for elf in input.split("\n\n") {
+        let mut calories = 0;
+        for line in elf.lines() {
+            calories += line.parse::<u64>().ok()?;
+        }
+        elves.push(calories);
+    }
+
+    elves.sort();
+    elves.reverse();
+    let top = match part {
+        Part::One => 1,
+        Part::Two => 3,
+    };
+    if elves.len() < top {
+        return None;
+    }
+    Some((elves[..top].iter().sum::<u64>() as i64).into())
+}
+
+// day2 ------------------------------------------------------------
+
+pub fn day2(part: Part, input: &str) -> Option<Answer> {
+    // 0 is rock, 1 paper and 2 scissors, each beaten by the next one
+    let beats = |a: usize, b: usize| (b + 1) % 3 == a;
+    let outcome_score = |ours: usize, theirs: usize| match (beats(ours, theirs), ours == theirs) {
+        (true, _) => 6,
+        (_, true) => 3,
+        _ => 0,
+    };
+
+    let mut score = 0;
+    for line in input.lines() {
+        let (theirs, second) = match line.as_bytes() {
+            [theirs @ b'A'..=b'C', b' ', second @ b'X'..=b'Z'] => {
+                ((theirs - b'A') as usize, (second - b'X') as usize)
+            }
+            _ => return None,
+        };
+
+        let ours = match part {
+            Part::One => second,
+            // try every shape until one gives the wanted outcome
+            Part::Two => (0..3).find(|&ours| outcome_score(ours, theirs) == second * 3)?,
+        };
+        score += ours + 1 + outcome_score(ours, theirs);
+    }
+
+    Some(score.into())
+}
+
+// day3 ------------------------------------------------------------
+
+fn priority(item: char) -> Option<usize> {
+    let items = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    items.find(item).map(|idx| idx + 1)
+}
+
+/// The only item that is in all of `groups`.
+fn only_common_item(groups: &[&str]) -> Option<char> {
+    let common: HashSet<char> = groups[0]
+        .chars()
+        .filter(|item| groups[1..].iter().all(|group| group.contains(*item)))
+        .collect();
+    match common.len() {
+        1 => common.into_iter().next(),
+        _ => None,
+    }
+}
+
+pub fn day3(part: Part, input: &str) -> Option<Answer> {
+    let rucksacks: Vec<&str> = input.lines().collect();
+    if rucksacks.iter().any(|rucksack| !rucksack.is_ascii()) {
+        return None;
+    }
+
+    let mut sum = 0;
+    match part {
+        Part::One => {
+            for rucksack in rucksacks {
+                if !rucksack.len().is_multiple_of(2) {
+                    return None;
+                }
+                let (left, right) = rucksack.split_at(rucksack.len() / 2);
+                sum += priority(only_common_item(&[left, right])?)?;
+            }
+        }
+        Part::Two => {
+            if !rucksacks.len().is_multiple_of(3) {
+                return None;
+            }
+            for group in rucksacks.chunks(3) {
+                sum += priority(only_common_item(group)?)?;
+            }
+        }
+    }
+
+    Some(sum.into())
+}
+
+// day4 ------------------------------------------------------------
+
+pub fn day4(part: Part, input: &str) -> Option<Answer> {
+    let sections = |range: &str| -> Option<HashSet<u32>> {
+        let (start, end) = range.split_once('-')?;
+        let (start, end): (u32, u32) = (start.parse().ok()?, end.parse().ok()?);
+        (start <= end).then(|| (start..=end).collect())
+    };
+
+    let mut count = 0;
+    for line in input.lines() {
+        let (first, second) = line.split_once(',')?;
+        let (first, second) = (sections(first)?, sections(second)?);
+        let counts = match part {
+            Part::One => first.is_subset(&second) || second.is_subset(&first),
+            Part::Two => !first.is_disjoint(&second),
+        };
+        if counts {
+            count += 1;
+        }
+    }
+
+    Some(count.into())
+}
+
+// day5 ------------------------------------------------------------
+
+pub fn day5(part: Part, input: &str) -> Option<Answer> {
+    let (diagram, moves) = input.split_once("\n\n")?;
+    let mut rows: Vec<&str> = diagram.lines().collect();
+    let labels = rows.pop()?;
+    let count = labels.split_whitespace().count();
+    if count == 0 {
+        return None;
+    }
+
+    // crate letters are in every fourth column, starting from the second
+    let mut stacks = vec![vec![]; count];
+    for row in rows.iter().rev() {
+        for (idx, stack) in stacks.iter_mut().enumerate() {
+            match row.chars().nth(1 + 4 * idx) {
+                Some(' ') | None => (),
+                Some(label) if label.is_ascii_uppercase() => stack.push(label),
+                Some(_) => return None,
+            }
+        }
+    }
+
+    for line in moves.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+        let [_, quantity, _, from, _, to] = words[..] else {
+            return None;
+        };
+        let quantity: usize = quantity.parse().ok()?;
+        let from = from.parse::<usize>().ok()?.checked_sub(1)?;
+        let to = to.parse::<usize>().ok()?.checked_sub(1)?;
+        if from == to || to >= count || stacks.get(from)?.len() < quantity {
+            return None;
+        }
+
+        let mut moved = vec![];
+        for _ in 0..quantity {
+            moved.push(stacks[from].pop()?);
+        }
+        if part == Part::Two {
+            moved.reverse();
+        }
+        stacks[to].extend(moved);
+    }
+
+    let tops: Option<String> = stacks.iter().map(|stack| stack.last()).collect();
+    Some(tops?.into())
+}
+
+// day6 ------------------------------------------------------------
+
+pub fn day6(part: Part, input: &str) -> Option<Answer> {
+    let length = match part {
+        Part::One => 4,
+        Part::Two => 14,
+    };
+    let stream: Vec<char> = input.trim().chars().collect();
+
+    (length..=stream.len())
+        .find(|&end| {
+            let window = &stream[end - length..end];
+            (0..length).all(|i| (0..length).all(|j| i == j || window[i] != window[j]))
+        })
+        .map(Answer::from)
+}
+
+// day7 ------------------------------------------------------------
+
+const DISK_SIZE: u64 = 70_000_000;
+const NEEDED_SPACE: u64 = 30_000_000;
+
+pub fn day7(part: Part, input: &str) -> Option<Answer> {
+    // every directory and file by its full path
+    let mut dirs: HashSet<Vec<&str>> = HashSet::from([vec![]]);
+    let mut files: BTreeMap<Vec<&str>, u64> = BTreeMap::new();
+    let mut cwd: Vec<&str> = vec![];
+
+    for line in input.lines() {
+        let words: Vec<&str> = line.split(' ').collect();
+        match words[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop()?;
+            }
+            ["$", "cd", dir] => {
+                cwd.push(dir);
+                if !dirs.contains(&cwd) {
+                    return None;
+                }
+            }
+            ["$", "ls"] => (),
+            ["dir", dir] => {
+                dirs.insert([&cwd[..], &[dir]].concat());
+            }
+            [size, file] => {
+                files.insert([&cwd[..], &[file]].concat(), size.parse().ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    let dir_size = |dir: &Vec<&str>| -> u64 {
+        files
+            .iter()
+            .filter(|(file, _)| file.starts_with(dir))
+            .map(|(_, size)| size)
+            .sum()
+    };
+    let sizes: Vec<u64> = dirs.iter().map(dir_size).collect();
+
+    let answer = match part {
+        Part::One => sizes.iter().filter(|size| **size <= 100_000).sum(),
+        Part::Two => {
+            let free = DISK_SIZE.checked_sub(dir_size(&vec![]))?;
+            // the puzzle always needs to delete something
+            let missing = NEEDED_SPACE
+                .checked_sub(free)
+                .filter(|missing| *missing > 0)?;
+            sizes.into_iter().filter(|size| *size >= missing).min()?
+        }
+    };
+    Some((answer as i64).into())
+}
+
+// day8 ------------------------------------------------------------
+
+pub fn day8(part: Part, input: &str) -> Option<Answer> {
+    let grid: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10)).collect())
+        .collect::<Option<_>>()?;
+    let height = grid.len();
+    let width = grid.first()?.len();
+    if width == 0 || grid.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    // the trees seen from (x, y) looking in every direction, nearest first
+    let lines_of_sight = |x: usize, y: usize| -> [Vec<u32>; 4] {
+        [
+            (0..x).rev().map(|i| grid[y][i]).collect(),
+            (x + 1..width).map(|i| grid[y][i]).collect(),
+            (0..y).rev().map(|i| grid[i][x]).collect(),
+            (y + 1..height).map(|i| grid[i][x]).collect(),
+        ]
+    };
+
+    let mut answer = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &tree) in row.iter().enumerate() {
+            let lines = lines_of_sight(x, y);
+            match part {
+                Part::One => {
+                    if lines
+                        .iter()
+                        .any(|line| line.iter().all(|other| *other < tree))
+                    {
+                        answer += 1;
+                    }
+                }
+                Part::Two => {
+                    let score: usize = lines
+                        .iter()
+                        .map(|line| match line.iter().position(|other| *other >= tree) {
+                            Some(blocking) => blocking + 1,
+                            None => line.len(),
+                        })
+                        .product();
+                    answer = answer.max(score);
+                }
+            }
+        }
+    }
+
+    Some(answer.into())
+}
+
+// day9 ------------------------------------------------------------
+
+pub fn day9(part: Part, input: &str) -> Option<Answer> {
+    let knots = match part {
+        Part::One => 2,
+        Part::Two => 10,
+    };
+    let mut rope = vec![(0i64, 0i64); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for line in input.lines() {
+        let (direction, steps) = line.split_once(' ')?;
+        let (dx, dy) = match direction {
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            "U" => (0, 1),
+            "D" => (0, -1),
+            _ => return None,
+        };
+
+        for _ in 0..steps.parse::<u32>().ok()? {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for idx in 1..knots {
+                let (head, knot) = (rope[idx - 1], rope[idx]);
+                let touching = (head.0 - knot.0).abs() <= 1 && (head.1 - knot.1).abs() <= 1;
+                if touching {
+                    continue;
+                }
+
+                // out of the knot and its neighbours, go to the one closest
+                // to the knot ahead
+                let distance = |(x, y): (i64, i64)| (head.0 - x).pow(2) + (head.1 - y).pow(2);
+                rope[idx] = (-1..=1)
+                    .flat_map(|dx| (-1..=1).map(move |dy| (knot.0 + dx, knot.1 + dy)))
+                    .min_by_key(|position| distance(*position))?;
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    Some(visited.len().into())
+}
+
+// day10 -----------------------------------------------------------
+
+pub fn day10(part: Part, input: &str) -> Option<Answer> {
+    // the value of X during every cycle, starting from the first
+    let mut x_values = vec![];
+    let mut x_register = 1i64;
+    for line in input.lines() {
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["noop"] => x_values.push(x_register),
+            ["addx", value] => {
+                x_values.extend([x_register, x_register]);
+                x_register += value.parse::<i64>().ok()?;
+            }
+            _ => return None,
+        }
+    }
+
+    match part {
+        Part::One => {
+            // cycles after the end of the program have no signal
+            let strength = |cycle: usize| match x_values.get(cycle - 1) {
+                Some(x) => cycle as i64 * x,
+                None => 0,
+            };
+            Some(
+                [20, 60, 100, 140, 180, 220]
+                    .map(strength)
+                    .iter()
+                    .sum::<i64>()
+                    .into(),
+            )
+        }
+        Part::Two => {
+            // pixel N is drawn during cycle N + 1, if the program runs that long
+            let pixels: Vec<char> = (0..240)
+                .map(|pixel| match x_values.get(pixel) {
+                    Some(x) if (x - (pixel % 40) as i64).abs() <= 1 => '#',
+                    _ => '.',
+                })
+                .collect();
+            let rows: Vec<String> = pixels.chunks(40).map(|row| row.iter().collect()).collect();
+            Some(rows.join("\n").into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::oracle;
+    use crate::{cli::Part, io_utils::read_example_for_day};
+
+    #[test]
+    fn test_oracles_solve_examples() {
+        for day in 1..=10 {
+            let example = read_example_for_day(day, 1).unwrap();
+            let expected = [(Part::One, example.part_one), (Part::Two, example.part_two)];
+            for (part, expected) in expected {
+                if let Some(expected) = expected {
+                    let actual = oracle(day).unwrap()(part, &example.input).map(|a| a.to_string());
+                    assert_eq!(actual, Some(expected), "Day {} {}", day, part);
+                }
+            }
+        }
+    }
+}
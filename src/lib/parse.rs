@@ -0,0 +1,51 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    num::ParseIntError,
+};
+
+/// Why a puzzle input couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line the error is on, counting from 1, when it is about a line.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attaches the index of the line (counting from 0) the error is on.
+    pub fn on_line(mut self, idx: usize) -> Self {
+        self.line = Some(idx + 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(format!("invalid number ({})", err))
+    }
+}
+
+/// For [`crate::Solution::parse`], which has no way to report an error:
+/// panics with the parse error's message.
+pub fn expect_valid<T>(parsed: Result<T, ParseError>) -> T {
+    parsed.unwrap_or_else(|err| panic!("invalid input, {}", err))
+}
@@ -0,0 +1,201 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answers::Answers,
+    io_utils::try_read_input_from_path,
+    runner::{run_input, PartRun, Selection, Status},
+};
+
+pub const PROFILES_DIR_ENV: &str = "AOC_PROFILES_DIR";
+
+/// Inputs of several accounts ("profiles"), laid out as
+///
+/// ```text
+/// inputs/
+///   alice.toml      answers for alice's inputs, formatted like answers.toml
+///   day1/alice.in
+///   day1/bob.in
+/// ```
+///
+/// in the crate's root, or in [`PROFILES_DIR_ENV`] if set.
+pub fn default_profiles_dir() -> PathBuf {
+    env::var_os(PROFILES_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+
+/// Profiles with an input for each day, found in `dir/dayN/<profile>.in`.
+pub fn discover(dir: &Path) -> io::Result<BTreeMap<u32, BTreeSet<String>>> {
+    let mut profiles: BTreeMap<u32, BTreeSet<String>> = BTreeMap::new();
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(profiles),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(day) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse().ok())
+        else {
+            continue;
+        };
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        for input in fs::read_dir(entry.path())? {
+            let path = input?.path();
+            if path.extension().is_some_and(|extension| extension == "in") {
+                if let Some(profile) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    profiles.entry(day).or_default().insert(profile.to_string());
+                }
+            }
+        }
+    }
+
+    Ok(profiles)
+}
+
+/// Results of running days against every profile's input.
+#[derive(Debug, Default)]
+pub struct Matrix {
+    pub profiles: BTreeSet<String>,
+    pub days: Vec<u32>,
+    pub cells: BTreeMap<(u32, String), Vec<PartRun>>,
+}
+
+impl Matrix {
+    /// Summary of a cell: the worst status of its parts, or `None` if the
+    /// profile has no input for the day.
+    pub fn status(&self, day: u32, profile: &str) -> Option<Status> {
+        let runs = self.cells.get(&(day, profile.to_string()))?;
+        [
+            Status::Failed,
+            Status::Wrong,
+            Status::Unchecked,
+            Status::Correct,
+        ]
+        .into_iter()
+        .find(|status| runs.iter().any(|run| run.status() == *status))
+    }
+
+    pub fn passed(&self) -> bool {
+        self.cells
+            .values()
+            .flatten()
+            .all(|run| !matches!(run.status(), Status::Wrong | Status::Failed))
+    }
+}
+
+/// Runs the selected days and parts on every profile's input in `dir`,
+/// comparing with the answers in `dir/<profile>.toml`.
+pub fn run_matrix(dir: &Path, selection: &Selection) -> Result<Matrix, String> {
+    let discovered =
+        discover(dir).map_err(|err| format!("could not read {}: {}", dir.display(), err))?;
+    let mut matrix = Matrix {
+        days: selection.days.clone(),
+        ..Matrix::default()
+    };
+    let mut answers: BTreeMap<String, Answers> = BTreeMap::new();
+
+    for &day in &selection.days {
+        for profile in discovered.get(&day).into_iter().flatten() {
+            matrix.profiles.insert(profile.clone());
+            if !answers.contains_key(profile) {
+                answers.insert(profile.clone(), load_answers(dir, profile)?);
+            }
+
+            let path = dir
+                .join(format!("day{}", day))
+                .join(format!("{}.in", profile));
+            let input = try_read_input_from_path(&path).map_err(|err| err.to_string());
+            let runs = selection
+                .parts()
+                .map(|part| run_input(day, part, &input, &answers[profile]))
+                .collect();
+            matrix.cells.insert((day, profile.clone()), runs);
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// A profile without an answers file just isn't checked.
+fn load_answers(dir: &Path, profile: &str) -> Result<Answers, String> {
+    let path = dir.join(format!("{}.toml", profile));
+    if !path.exists() {
+        return Ok(Answers::default());
+    }
+    Answers::load_from_path(path).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{default_profiles_dir, discover, run_matrix};
+    use crate::runner::{Selection, Status};
+
+    #[test]
+    fn test_matrix() {
+        let dir = env::temp_dir().join(format!("aoc_profiles_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("day1")).unwrap();
+        fs::create_dir_all(dir.join("day4")).unwrap();
+        fs::write(dir.join("day1/alice.in"), "1\n2\n\n3\n\n4").unwrap();
+        fs::write(dir.join("day1/bob.in"), "10\n\n20\n\n30").unwrap();
+        fs::write(dir.join("day1/notes.txt"), "not an input").unwrap();
+        fs::write(dir.join("day4/bob.in"), "2-4,6-8\n2-8,3-7").unwrap();
+        fs::write(
+            dir.join("alice.toml"),
+            "[day1]\npart_one = 4\npart_two = 10",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("bob.toml"),
+            "[day1]\npart_one = 1\n[day4]\npart_one = 1",
+        )
+        .unwrap();
+
+        let discovered = discover(&dir).unwrap();
+        assert_eq!(discovered.len(), 2);
+        assert_eq!(discovered[&1].len(), 2);
+
+        let selection = Selection {
+            days: vec![1, 4],
+            part: None,
+        };
+        let matrix = run_matrix(&dir, &selection).unwrap();
+        assert_eq!(matrix.status(1, "alice"), Some(Status::Correct));
+        assert_eq!(matrix.status(1, "bob"), Some(Status::Wrong));
+        assert_eq!(matrix.status(4, "bob"), Some(Status::Unchecked));
+        assert_eq!(matrix.status(4, "alice"), None);
+        assert!(!matrix.passed());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_repository_profiles() {
+        let matrix = run_matrix(&default_profiles_dir(), &Selection::all()).unwrap();
+        let failures: Vec<String> = matrix
+            .cells
+            .iter()
+            .filter(|(_, runs)| {
+                runs.iter()
+                    .any(|run| matches!(run.status(), Status::Wrong | Status::Failed))
+            })
+            .map(|((day, profile), _)| format!("day {} of {}", day, profile))
+            .collect();
+
+        assert!(failures.is_empty(), "Failed: {}", failures.join(", "));
+    }
+}
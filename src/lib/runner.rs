@@ -0,0 +1,294 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::Answers,
+    cli::Part,
+    days::{self, DAYS},
+    io_utils::try_read_input_for_day,
+    Answer, DynSolution,
+};
+
+/// Which days and parts to run, e.g. `4`, `1..10` or `--all`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub days: Vec<u32>,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    pub fn all() -> Self {
+        Self {
+            days: DAYS.iter().map(|solution| solution.day()).collect(),
+            part: None,
+        }
+    }
+
+    /// Adds a day (`4`) or an inclusive range of days (`1..10` or `1..=10`).
+    pub fn add(&mut self, days: &str) -> Result<(), String> {
+        for day in parse_days(days)? {
+            if days::get(day).is_none() {
+                return Err(format!("day {} is not solved yet", day));
+            }
+            if !self.days.contains(&day) {
+                self.days.push(day);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = Part> + '_ {
+        Part::ALL
+            .into_iter()
+            .filter(|part| self.part.is_none_or(|selected| selected == *part))
+    }
+}
+
+fn parse_days(days: &str) -> Result<RangeInclusive<u32>, String> {
+    let invalid = || format!("invalid day or range {:?}", days);
+    let parse = |day: &str| day.parse::<u32>().map_err(|_| invalid());
+
+    let range = match days.split_once("..") {
+        Some((from, to)) => parse(from)?..=parse(to.strip_prefix('=').unwrap_or(to))?,
+        None => parse(days)?..=parse(days)?,
+    };
+    if range.is_empty() {
+        return Err(invalid());
+    }
+    Ok(range)
+}
+
+/// The result of running one part of a day.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub day: u32,
+    pub part: Part,
+    /// The answer, or why there is none (a panic or a missing input).
+    pub outcome: Result<Answer, String>,
+    pub elapsed: Duration,
+    pub expected: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    /// There is no recorded answer to compare with, or the part isn't
+    /// solved yet.
+    Unchecked,
+    Failed,
+}
+
+impl PartRun {
+    pub fn status(&self) -> Status {
+        match (&self.outcome, &self.expected) {
+            (Err(_), _) => Status::Failed,
+            (Ok(Answer::Unsolved), _) | (Ok(_), None) => Status::Unchecked,
+            (Ok(answer), Some(expected)) if answer.to_string() == *expected => Status::Correct,
+            (Ok(_), Some(_)) => Status::Wrong,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Correct => f.pad("ok"),
+            Status::Wrong => f.pad("WRONG"),
+            Status::Unchecked => f.pad("?"),
+            Status::Failed => f.pad("FAILED"),
+        }
+    }
+}
+
+/// Runs a part on the given input, catching panics. The elapsed time
+/// includes parsing.
+pub fn run_part(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &str,
+) -> (Result<Answer, String>, Duration) {
+    let start = Instant::now();
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| solution.run(part, input)))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())));
+    (outcome, start.elapsed())
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Runs a part on an already read input (or the error reading it).
+pub(crate) fn run_input(
+    day: u32,
+    part: Part,
+    input: &Result<String, String>,
+    answers: &Answers,
+) -> PartRun {
+    let solution = days::get(day).unwrap_or_else(|| panic!("day {} is not solved yet", day));
+    let (outcome, elapsed) = match input {
+        Ok(input) => run_part(solution, part, input),
+        Err(err) => (Err(err.clone()), Duration::ZERO),
+    };
+
+    PartRun {
+        day,
+        part,
+        outcome,
+        elapsed,
+        expected: answers.get(day, part).map(String::from),
+    }
+}
+
+fn read_input(day: u32) -> Result<String, String> {
+    try_read_input_for_day(day).map_err(|err| err.to_string())
+}
+
+/// Runs the selected parts of a day on its `input.in`.
+pub fn run_day(day: u32, selection: &Selection, answers: &Answers) -> Vec<PartRun> {
+    let input = read_input(day);
+
+    selection
+        .parts()
+        .map(|part| run_input(day, part, &input, answers))
+        .collect()
+}
+
+/// Runs every selected day and part on a pool of `jobs` threads. A panic
+/// only fails its own part; the results are ordered by day and part.
+pub fn run_parallel(selection: &Selection, answers: &Answers, jobs: usize) -> Vec<PartRun> {
+    let queue: Vec<(u32, Part)> = selection
+        .days
+        .iter()
+        .flat_map(|&day| selection.parts().map(move |part| (day, part)))
+        .collect();
+    let queue = Mutex::new(queue.into_iter());
+    let results = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| loop {
+                let Some((day, part)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let run = run_input(day, part, &read_input(day), answers);
+                results.lock().unwrap().push(run);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|run| (run.day, run.part));
+    results
+}
+
+/// Formats a duration with a unit fitting its size, e.g. `12.3ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_duration, run_parallel, run_part, PartRun, Selection, Status};
+    use crate::{answers::Answers, cli::Part, days, Answer};
+
+    #[test]
+    fn test_selection() {
+        let mut selection = Selection {
+            days: vec![],
+            part: None,
+        };
+        selection.add("3..5").unwrap();
+        selection.add("1").unwrap();
+        selection.add("4..=6").unwrap();
+        assert_eq!(selection.days, vec![3, 4, 5, 1, 6]);
+        assert_eq!(selection.parts().count(), 2);
+
+        assert!(selection.add("5..3").is_err());
+        assert!(selection.add("x").is_err());
+        assert!(selection.add("1..30").is_err());
+        assert_eq!(Selection::all().days.len(), days::DAYS.len());
+    }
+
+    #[test]
+    fn test_status_and_panics() {
+        let day1 = days::get(1).unwrap();
+        let (outcome, _) = run_part(day1, Part::One, "1000\n2000\n\n500");
+        let run = |expected: Option<&str>| PartRun {
+            day: 1,
+            part: Part::One,
+            outcome: outcome.clone(),
+            elapsed: Duration::ZERO,
+            expected: expected.map(String::from),
+        };
+        assert_eq!(run(Some("3000")).status(), Status::Correct);
+        assert_eq!(run(Some("500")).status(), Status::Wrong);
+        assert_eq!(run(None).status(), Status::Unchecked);
+
+        let unsolved = PartRun {
+            outcome: Ok(Answer::Unsolved),
+            ..run(Some("unsolved"))
+        };
+        assert_eq!(unsolved.status(), Status::Unchecked);
+
+        let (outcome, _) = run_part(day1, Part::One, "not a number");
+        assert!(outcome.unwrap_err().starts_with("panicked: "));
+    }
+
+    #[test]
+    fn test_parallel_run() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::Two, "0".to_string());
+        let selection = Selection {
+            days: vec![6, 1, 4],
+            part: None,
+        };
+
+        let runs = run_parallel(&selection, &answers, 4);
+        let order: Vec<(u32, Part)> = runs.iter().map(|run| (run.day, run.part)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (1, Part::One),
+                (1, Part::Two),
+                (4, Part::One),
+                (4, Part::Two),
+                (6, Part::One),
+                (6, Part::Two)
+            ]
+        );
+        assert_eq!(runs[0].status(), Status::Unchecked);
+        assert_eq!(runs[1].status(), Status::Wrong);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+    }
+}
@@ -0,0 +1,286 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+const SOLUTION_TEMPLATE: &str = r#"use crate::{Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u32 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.lines().map(String::from).collect()
+    }
+
+    fn part_one(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day{day};
+    use crate::{answers::check_answer, cli::Part, io_utils::read_input_for_day, Solution};
+
+    #[test]
+    #[ignore = "fill in input.in and answers.toml first"]
+    fn test_part_one() {
+        let input = Day{day}.parse(&read_input_for_day({day}));
+        check_answer({day}, Part::One, Day{day}.part_one(&input));
+    }
+
+    #[test]
+    #[ignore = "fill in input.in and answers.toml first"]
+    fn test_part_two() {
+        let input = Day{day}.parse(&read_input_for_day({day}));
+        check_answer({day}, Part::Two, Day{day}.part_two(&input));
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use lib::{cli::run_day, days::day{day}::Day{day}};
+
+fn main() {
+    run_day(&Day{day});
+}
+"#;
+
+const EXAMPLE_ANSWERS_TEMPLATE: &str = "# answers published with the example, e.g.
+# part_one = 42
+";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u32),
+    DayExists(PathBuf),
+    /// `days/mod.rs` doesn't look the way the generator expects.
+    UnexpectedRegistry(PathBuf),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "invalid day {}, expected 1 to 25", day),
+            ScaffoldError::DayExists(path) => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            ScaffoldError::UnexpectedRegistry(path) => {
+                write!(f, "could not find the list of days in {}", path.display())
+            }
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Creates a new day in the crate at `root`:
+///
+/// - `src/lib/days/dayN.rs` with a [`crate::Solution`] and ignored tests
+///   checking it against `answers.toml`, registered in `src/lib/days/mod.rs`,
+/// - `src/bin/dayN/main.rs` running it,
+/// - empty `input.in` and `example1.in` with an `example1.answers` stub.
+///
+/// Refuses to touch a day that already has any of these. Returns the created
+/// and modified files.
+pub fn new_day(day: u32, root: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let bin_dir = root.join("src/bin").join(format!("day{}", day));
+    let days_dir = root.join("src/lib/days");
+    let solution_path = days_dir.join(format!("day{}.rs", day));
+    let registry_path = days_dir.join("mod.rs");
+
+    for path in [&bin_dir, &solution_path] {
+        if path.exists() {
+            return Err(ScaffoldError::DayExists(path.clone()));
+        }
+    }
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| ScaffoldError::Io { path, source }
+    };
+    let registry = fs::read_to_string(&registry_path).map_err(io_error(&registry_path))?;
+    let registry = register_day(&registry, day)
+        .ok_or_else(|| ScaffoldError::UnexpectedRegistry(registry_path.clone()))?;
+
+    let render = |template: &str| template.replace("{day}", &day.to_string());
+    let files = [
+        (solution_path, render(SOLUTION_TEMPLATE)),
+        (bin_dir.join("main.rs"), render(MAIN_TEMPLATE)),
+        (bin_dir.join("input.in"), String::new()),
+        (bin_dir.join("example1.in"), String::new()),
+        (
+            bin_dir.join("example1.answers"),
+            EXAMPLE_ANSWERS_TEMPLATE.to_string(),
+        ),
+        (registry_path, registry),
+    ];
+
+    fs::create_dir_all(&bin_dir).map_err(io_error(&bin_dir))?;
+    for (path, content) in &files {
+        fs::write(path, content).map_err(io_error(path))?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Adds `pub mod dayN;` and `&dayN::DayN` to the source of `days/mod.rs`.
+/// Returns `None` if the day is already there or the source is unexpected.
+fn register_day(registry: &str, day: u32) -> Option<String> {
+    let name = format!("day{}", day);
+    let module = format!("pub mod {};", name);
+    if registry.lines().any(|line| line == module) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    // modules are kept sorted the way rustfmt sorts them
+    let modules = lines.iter().position(|line| line.starts_with("pub mod "))?;
+    let modules_end = modules
+        + lines[modules..]
+            .iter()
+            .take_while(|line| line.starts_with("pub mod "))
+            .count();
+    let position = modules
+        + lines[modules..modules_end]
+            .iter()
+            .take_while(|line| line["pub mod ".len()..].trim_end_matches(';') < name.as_str())
+            .count();
+    lines.insert(position, module);
+
+    let days = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))?;
+    let days_end = days + lines[days..].iter().position(|line| line == "];")?;
+    lines.insert(days_end, format!("    &{}::Day{},", name, day));
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        path::{Path, PathBuf},
+    };
+
+    use super::{new_day, register_day, ScaffoldError};
+    use crate::client::{fetch_input, mock_server, ClientConfig, Fetched};
+
+    fn synthetic_crate(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/lib/days")).unwrap();
+        fs::write(
+            root.join("src/lib/days/mod.rs"),
+            "pub mod day1;\n\npub static DAYS: &[&dyn DynSolution] = &[\n    &day1::Day1,\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry =
+            "pub mod day1;\npub mod day10;\npub mod day2;\n\nuse crate::DynSolution;\n\n\
+                        pub static DAYS: &[&dyn DynSolution] = &[\n    &day1::Day1,\n];\n";
+        let registered = register_day(registry, 11).unwrap();
+        assert_eq!(
+            registered,
+            "pub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;\n\nuse crate::DynSolution;\n\n\
+             pub static DAYS: &[&dyn DynSolution] = &[\n    &day1::Day1,\n    &day11::Day11,\n];\n"
+        );
+        assert_eq!(register_day(&registered, 11), None);
+        assert_eq!(register_day("fn main() {}", 11), None);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = synthetic_crate("scaffold");
+
+        let created = new_day(11, &root).unwrap();
+        assert_eq!(created.len(), 6);
+        let solution = fs::read_to_string(root.join("src/lib/days/day11.rs")).unwrap();
+        assert!(solution.contains("impl Solution for Day11"));
+        assert!(solution.contains("check_answer(11, Part::One"));
+        assert!(solution.contains("Answer::Unsolved") && !solution.contains("todo!"));
+        let main = fs::read_to_string(root.join("src/bin/day11/main.rs")).unwrap();
+        assert!(main.contains("run_day(&Day11)"));
+        let registry = fs::read_to_string(root.join("src/lib/days/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day11;") && registry.contains("&day11::Day11,"));
+
+        assert!(matches!(
+            new_day(11, &root),
+            Err(ScaffoldError::DayExists(_))
+        ));
+        assert!(matches!(
+            new_day(26, &root),
+            Err(ScaffoldError::InvalidDay(26))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_after_new_day() {
+        let root = synthetic_crate("scaffold_fetch");
+        new_day(11, &root).unwrap();
+
+        let (base_url, requests) = mock_server::serve(|_| (200, "noop\n".to_string()));
+        let config = ClientConfig {
+            base_url,
+            session: Some("secret".to_string()),
+            year: 2022,
+            input_dir: root.join("src/bin"),
+        };
+        let input = Path::new("src/bin/day11/input.in");
+
+        // the empty input `aoc new` leaves isn't a cached one
+        assert_eq!(
+            fetch_input(11, &config).unwrap(),
+            Fetched::Downloaded(root.join(input))
+        );
+        assert_eq!(fs::read_to_string(root.join(input)).unwrap(), "noop\n");
+        assert_eq!(
+            fetch_input(11, &config).unwrap(),
+            Fetched::Cached(root.join(input))
+        );
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        fs::write(root.join(input), " \n").unwrap();
+        assert!(matches!(
+            fetch_input(11, &config).unwrap(),
+            Fetched::Downloaded(_)
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
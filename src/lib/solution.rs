@@ -0,0 +1,148 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+};
+
+use crate::{cli::Part, io_utils::normalize_input};
+
+/// Result of a single part. Most puzzles have numeric answers, a few are
+/// strings (e.g. crate labels or letters drawn on a screen).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// What a part scaffolded by `aoc new` returns until it is solved.
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => f.pad("unsolved"),
+        }
+    }
+}
+
+impl From<&Answer> for serde_json::Value {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(number) => (*number).into(),
+            Answer::Text(text) => text.as_str().into(),
+            Answer::Unsolved => serde_json::Value::Null,
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Self {
+        Answer::Number(number)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(number: i32) -> Self {
+        Answer::Number(number.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Self {
+        Answer::Number(number.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// A day's puzzle: parsing the input once and solving both parts from it.
+pub trait Solution: Sync {
+    const DAY: u32;
+
+    /// Whether [`Solution::stream_part`] solves a part without keeping the
+    /// whole input in memory, so runners should prefer it for big inputs.
+    const STREAMING: bool = false;
+
+    type Input: 'static;
+
+    fn parse(&self, input: &str) -> Self::Input;
+
+    fn part_one(&self, input: &Self::Input) -> Answer;
+
+    fn part_two(&self, input: &Self::Input) -> Answer;
+
+    /// Solves a part straight from the lines of the input. By default the
+    /// lines are collected and parsed as a whole.
+    fn stream_part(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        let input = normalize_input(&lines.collect::<Vec<_>>().join("\n"));
+        let input = self.parse(&input);
+        match part {
+            Part::One => self.part_one(&input),
+            Part::Two => self.part_two(&input),
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input
+/// types can be stored in one registry and run generically.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u32;
+
+    fn streaming(&self) -> bool;
+
+    fn parse_any(&self, input: &str) -> Box<dyn Any>;
+
+    /// Solves a part from the result of [`DynSolution::parse_any`].
+    fn run_parsed(&self, part: Part, input: &dyn Any) -> Answer;
+
+    fn run_lines(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer;
+
+    fn run(&self, part: Part, input: &str) -> Answer {
+        self.run_parsed(part, self.parse_any(input).as_ref())
+    }
+}
+
+impl<S: Solution> DynSolution for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn streaming(&self) -> bool {
+        S::STREAMING
+    }
+
+    fn parse_any(&self, input: &str) -> Box<dyn Any> {
+        Box::new(self.parse(input))
+    }
+
+    fn run_parsed(&self, part: Part, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Input of day {} has an unexpected type", S::DAY));
+
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    fn run_lines(&self, part: Part, lines: &mut dyn Iterator<Item = String>) -> Answer {
+        self.stream_part(part, lines)
+    }
+}
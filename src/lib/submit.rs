@@ -0,0 +1,433 @@
+use std::{
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    cli::Part,
+    client::{ClientConfig, ClientError},
+};
+
+const HISTORY_FILE: &str = "submissions.history";
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved or isn't unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Parses the `<article>` of the page returned after posting an answer.
+    pub fn from_response(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("too high") {
+            Verdict::TooHigh
+        } else if body.contains("too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            let wait = body
+                .split("You have ")
+                .nth(1)
+                .and_then(|rest| rest.split(" left to wait").next())
+                .map(String::from);
+            Verdict::RateLimited { wait }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the answer was checked and turned out wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited { .. } => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Some(match key {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "rate_limited" => Verdict::RateLimited { wait: None },
+            "wrong_level" => Verdict::WrongLevel,
+            "unknown" => Verdict::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "part already solved or locked"),
+            Verdict::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// A single submitted answer, as stored in the day's history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// All answers ever submitted for a day, stored as tab-separated
+/// `timestamp part verdict answer` lines in `dayN/submissions.history`.
+/// Tabs, newlines and backslashes in answers (e.g. letters drawn on a
+/// screen) are escaped, so every attempt stays on one line.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(day: u32, config: &ClientConfig) -> Result<Self, ClientError> {
+        let path = config.day_dir(day).join(HISTORY_FILE);
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(ClientError::Io { path, source }),
+        };
+
+        let attempts = content.lines().filter_map(parse_attempt).collect();
+        Ok(Self { path, attempts })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Verdict the website would give based on earlier attempts, if known.
+    ///
+    /// Besides exact matches, numeric answers are compared against earlier
+    /// "too high" and "too low" responses.
+    pub fn known_verdict(&self, part: Part, answer: &str) -> Option<Verdict> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.part == part)
+        };
+
+        if let Some(attempt) = attempts().find(|attempt| attempt.answer == answer) {
+            if attempt.verdict.is_wrong() || attempt.verdict == Verdict::Correct {
+                return Some(attempt.verdict.clone());
+            }
+        }
+        if attempts().any(|attempt| attempt.verdict == Verdict::Correct) {
+            return Some(Verdict::Incorrect);
+        }
+
+        let answer: i64 = answer.parse().ok()?;
+        let bound = |verdict: Verdict| {
+            attempts()
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i64>().ok())
+        };
+        if bound(Verdict::TooHigh).any(|too_high| answer >= too_high) {
+            return Some(Verdict::TooHigh);
+        }
+        if bound(Verdict::TooLow).any(|too_low| answer <= too_low) {
+            return Some(Verdict::TooLow);
+        }
+
+        None
+    }
+
+    fn record(&mut self, attempt: Attempt) -> Result<(), ClientError> {
+        let io_error = |source| ClientError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            attempt.timestamp,
+            attempt.part.number(),
+            attempt.verdict.key(),
+            escape(&attempt.answer)
+        )
+        .map_err(io_error)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.splitn(4, '\t');
+    Some(Attempt {
+        timestamp: fields.next()?.parse().ok()?,
+        part: Part::from_number(fields.next()?.parse().ok()?)?,
+        verdict: Verdict::from_key(fields.next()?)?,
+        answer: unescape(fields.next()?)?,
+    })
+}
+
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `None` if the field contains an unknown escape.
+fn unescape(field: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        unescaped.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        });
+    }
+    Some(unescaped)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Submission {
+    /// The answer was posted and the verdict recorded in the history.
+    Posted(Verdict),
+    /// The history already tells how the answer would be judged, so it was
+    /// not posted again.
+    Known(Verdict),
+}
+
+/// Posts `answer` for the given part, unless the day's history already
+/// knows the verdict for it.
+pub fn submit_answer(
+    day: u32,
+    part: Part,
+    answer: &str,
+    config: &ClientConfig,
+) -> Result<Submission, ClientError> {
+    let answer = answer.trim();
+    let mut history = History::load(day, config)?;
+    if let Some(verdict) = history.known_verdict(part, answer) {
+        return Ok(Submission::Known(verdict));
+    }
+
+    let level = part.number().to_string();
+    let body = config.post_form(
+        &config.day_url(day, "/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let verdict = Verdict::from_response(&body);
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    history.record(Attempt {
+        timestamp,
+        part,
+        answer: answer.to_string(),
+        verdict: verdict.clone(),
+    })?;
+
+    Ok(Submission::Posted(verdict))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::{submit_answer, Attempt, History, Submission, Verdict};
+    use crate::{
+        cli::Part,
+        client::{mock_server, ClientConfig},
+    };
+
+    #[test]
+    fn test_parsing_responses() {
+        let cases = [
+            (
+                "<p>That's the right answer! You are one gold star closer.</p>",
+                Verdict::Correct,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too high.</p>",
+                Verdict::TooHigh,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too low.</p>",
+                Verdict::TooLow,
+            ),
+            (
+                "<p>That's not the right answer. If you're stuck...</p>",
+                Verdict::Incorrect,
+            ),
+            (
+                "<p>You gave an answer too recently. You have 35s left to wait.</p>",
+                Verdict::RateLimited {
+                    wait: Some("35s".to_string()),
+                },
+            ),
+            (
+                "<p>You don't seem to be solving the right level.</p>",
+                Verdict::WrongLevel,
+            ),
+            ("<p>???</p>", Verdict::Unknown),
+        ];
+
+        for (body, verdict) in cases {
+            assert_eq!(Verdict::from_response(body), verdict);
+        }
+    }
+
+    #[test]
+    fn test_submitting_records_history() {
+        let (base_url, requests) = mock_server::serve(|request| {
+            let body = if request.contains("answer=100") {
+                "That's not the right answer; your answer is too high."
+            } else {
+                "That's the right answer!"
+            };
+            (200, body.to_string())
+        });
+        let input_dir = env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        let config = ClientConfig {
+            base_url,
+            session: Some("secret".to_string()),
+            year: 2022,
+            input_dir,
+        };
+
+        assert_eq!(
+            submit_answer(3, Part::One, "100", &config).unwrap(),
+            Submission::Posted(Verdict::TooHigh)
+        );
+        // same answer and anything higher are known to be wrong
+        assert_eq!(
+            submit_answer(3, Part::One, "100", &config).unwrap(),
+            Submission::Known(Verdict::TooHigh)
+        );
+        assert_eq!(
+            submit_answer(3, Part::One, "150", &config).unwrap(),
+            Submission::Known(Verdict::TooHigh)
+        );
+        assert_eq!(
+            submit_answer(3, Part::One, "42", &config).unwrap(),
+            Submission::Posted(Verdict::Correct)
+        );
+        assert_eq!(
+            submit_answer(3, Part::One, "43", &config).unwrap(),
+            Submission::Known(Verdict::Incorrect)
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2022/day/3/answer "));
+        assert!(requests[0].ends_with("level=1&answer=100"));
+
+        let history = History::load(3, &config).unwrap();
+        assert_eq!(history.attempts.len(), 2);
+        assert_eq!(history.attempts[1].answer, "42");
+        assert_eq!(history.attempts[1].verdict, Verdict::Correct);
+
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_multi_line_answers_in_history() {
+        let input_dir = env::temp_dir().join(format!("aoc_history_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        let config = ClientConfig {
+            base_url: String::new(),
+            session: None,
+            year: 2022,
+            input_dir,
+        };
+
+        let attempts = [
+            ("##..#\n#..\\n\tx", Verdict::Incorrect),
+            ("ZKJFBJFZ", Verdict::Correct),
+        ];
+        let mut history = History::load(10, &config).unwrap();
+        for (timestamp, (answer, verdict)) in attempts.iter().enumerate() {
+            history
+                .record(Attempt {
+                    timestamp: timestamp as u64,
+                    part: Part::Two,
+                    answer: answer.to_string(),
+                    verdict: verdict.clone(),
+                })
+                .unwrap();
+        }
+
+        let content = fs::read_to_string(history.path()).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        let loaded = History::load(10, &config).unwrap();
+        assert_eq!(loaded.attempts, history.attempts);
+
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+}
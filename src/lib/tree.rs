@@ -1,86 +1,382 @@
-use std::{collections::HashMap, fmt::Debug};
-
-use uuid::Uuid;
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Debug, Display},
+    ops::Index,
+};
 
+/// A tree (or forest) whose nodes live in one contiguous arena.
+///
+/// Removed nodes leave a free slot that later nodes reuse. Every slot counts
+/// how often it was reused, so a [`NodeId`] of a removed node never refers
+/// to the node that took its place.
 #[derive(Debug)]
 pub struct Tree<T: Debug> {
-    pub nodes: HashMap<Uuid, Node<T>>,
+    slots: Vec<Slot<T>>,
+    /// indices of the free slots
+    free: Vec<u32>,
+    len: usize,
 }
 
-impl<T: Debug> Tree<T> {
-    pub fn default() -> Self {
+#[derive(Debug)]
+struct Slot<T: Debug> {
+    generation: u32,
+    node: Option<Node<T>>,
+}
+
+impl<T: Debug> Default for Tree<T> {
+    fn default() -> Self {
         Self {
-            nodes: HashMap::new(),
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
         }
     }
+}
+
+/// Why an operation on a [`Tree`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeError {
+    MissingNode(NodeId),
+    MissingParent(NodeId),
+    /// Moving the node below the parent would put the node in its own
+    /// subtree.
+    WouldCreateCycle {
+        node: NodeId,
+        new_parent: NodeId,
+    },
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeError::MissingNode(id) => write!(f, "node doesn't exist {}", id),
+            TreeError::MissingParent(id) => write!(f, "parent doesn't exist {}", id),
+            TreeError::WouldCreateCycle { node, new_parent } => {
+                write!(f, "{} is in the subtree of {}", new_parent, node)
+            }
+        }
+    }
+}
 
+impl Error for TreeError {}
+
+impl<T: Debug> Tree<T> {
     pub fn add_node(&mut self, parent: Option<NodeId>, data: T) -> NodeId {
-        let new_id = Uuid::new_v4();
-
-        self.nodes.insert(
-            new_id,
-            Node {
-                parent,
-                children: Vec::new(),
-                data,
-            },
-        );
+        self.try_add_node(parent, data)
+            .unwrap_or_else(|err| panic!("Error while inserting a node, {}", err))
+    }
+
+    pub fn try_add_node(&mut self, parent: Option<NodeId>, data: T) -> Result<NodeId, TreeError> {
+        if let Some(parent_id) = parent {
+            if self.get_node(&parent_id).is_none() {
+                return Err(TreeError::MissingParent(parent_id));
+            }
+        }
+
+        let node = Node {
+            parent,
+            children: Vec::new(),
+            data,
+        };
+        let new_id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.node = Some(node);
+                NodeId {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                let index = u32::try_from(self.slots.len()).expect("too many nodes");
+                self.slots.push(Slot {
+                    generation: 0,
+                    node: Some(node),
+                });
+                NodeId {
+                    index,
+                    generation: 0,
+                }
+            }
+        };
+        self.len += 1;
 
         if let Some(parent_id) = parent {
-            self.nodes
-                .get_mut(&parent_id)
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Error while inserting a node, parent doesn't exist {}",
-                        parent_id
-                    )
-                })
-                .children
-                .push(new_id);
+            self.get_node_mut(&parent_id).unwrap().children.push(new_id);
         }
 
-        new_id
+        Ok(new_id)
     }
 
+    /// Removes a node, making its children roots. See [`Tree::remove_subtree`]
+    /// to remove them too. Does nothing if the node doesn't exist.
     pub fn remove_node(&mut self, node_id: NodeId) {
-        let node = self.nodes.remove(&node_id);
-        match node {
-            None => (),
-            Some(node) => {
-                // remove node from it's parent
-                if let Some(parent_id) = node.parent {
-                    if let Some(parent) = self.nodes.get_mut(&parent_id) {
-                        parent.children.retain(|el| el != &node_id)
-                    }
-                }
+        let _ = self.try_remove_node(node_id);
+    }
+
+    /// Removes a node like [`Tree::remove_node`], returning its data.
+    pub fn try_remove_node(&mut self, node_id: NodeId) -> Result<T, TreeError> {
+        let node = self
+            .take_node(node_id)
+            .ok_or(TreeError::MissingNode(node_id))?;
+
+        // remove node from it's parent
+        if let Some(parent_id) = node.parent {
+            if let Some(parent) = self.get_node_mut(&parent_id) {
+                parent.children.retain(|el| el != &node_id)
+            }
+        }
+
+        // set node's children to None
+        node.children.iter().for_each(|id| {
+            if let Some(child_node) = self.get_node_mut(id) {
+                child_node.parent = None;
+            }
+        });
+
+        Ok(node.data)
+    }
+
+    /// Removes a node and all its descendants, returning their data in
+    /// pre-order. Nothing if the node doesn't exist.
+    pub fn remove_subtree(&mut self, node_id: NodeId) -> Vec<T> {
+        self.try_remove_subtree(node_id).unwrap_or_default()
+    }
+
+    pub fn try_remove_subtree(&mut self, node_id: NodeId) -> Result<Vec<T>, TreeError> {
+        self.try_detach(node_id)?;
+
+        let ids: Vec<NodeId> = self.pre_order(node_id).map(|(id, _, _)| id).collect();
+        Ok(ids
+            .into_iter()
+            .map(|id| self.take_node(id).unwrap().data)
+            .collect())
+    }
+
+    /// Frees the node's slot, leaving its parent and children pointing at it.
+    fn take_node(&mut self, node_id: NodeId) -> Option<Node<T>> {
+        self.get_node(&node_id)?;
+        let slot = &mut self.slots[node_id.index as usize];
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(node_id.index);
+        self.len -= 1;
+        slot.node.take()
+    }
+
+    /// Moves a node, with its subtree, below `new_parent`, as its last child.
+    pub fn move_node(&mut self, node_id: NodeId, new_parent: NodeId) {
+        self.try_move_node(node_id, new_parent)
+            .unwrap_or_else(|err| panic!("Error while moving a node, {}", err))
+    }
+
+    pub fn try_move_node(&mut self, node_id: NodeId, new_parent: NodeId) -> Result<(), TreeError> {
+        if self.get_node(&node_id).is_none() {
+            return Err(TreeError::MissingNode(node_id));
+        }
+        if self.get_node(&new_parent).is_none() {
+            return Err(TreeError::MissingParent(new_parent));
+        }
+        if self.is_ancestor(node_id, new_parent) {
+            return Err(TreeError::WouldCreateCycle {
+                node: node_id,
+                new_parent,
+            });
+        }
 
-                // set node's children to None
-                node.children.iter().for_each(|id| {
-                    if let Some(child_node) = self.nodes.get_mut(id) {
-                        child_node.parent = None;
-                    }
-                })
+        self.try_detach(node_id)?;
+        self.get_node_mut(&node_id).unwrap().parent = Some(new_parent);
+        self.get_node_mut(&new_parent)
+            .unwrap()
+            .children
+            .push(node_id);
+        Ok(())
+    }
+
+    /// Whether `ancestor` is `node_id` or one of its ancestors.
+    fn is_ancestor(&self, ancestor: NodeId, node_id: NodeId) -> bool {
+        let mut current = Some(node_id);
+        while let Some(id) = current {
+            if id == ancestor {
+                return true;
             }
+            current = self.get_node(&id).and_then(|node| node.parent);
         }
+        false
+    }
+
+    /// Cuts a node, with its subtree, from its parent, making it a root.
+    pub fn detach(&mut self, node_id: NodeId) {
+        self.try_detach(node_id)
+            .unwrap_or_else(|err| panic!("Error while detaching a node, {}", err))
+    }
+
+    pub fn try_detach(&mut self, node_id: NodeId) -> Result<(), TreeError> {
+        let node = self
+            .get_node_mut(&node_id)
+            .ok_or(TreeError::MissingNode(node_id))?;
+
+        if let Some(parent_id) = node.parent.take() {
+            if let Some(parent) = self.get_node_mut(&parent_id) {
+                parent.children.retain(|el| el != &node_id)
+            }
+        }
+        Ok(())
     }
 
     pub fn update_node(&mut self, node_id: NodeId, update_fn: &dyn Fn(&mut Node<T>)) {
-        let node = self.nodes.get_mut(&node_id).unwrap_or_else(|| {
-            panic!(
-                "Error while inserting a node, parent doesn't exist {}",
-                node_id
-            )
-        });
+        self.try_update_node(node_id, update_fn)
+            .unwrap_or_else(|err| panic!("Error while updating a node, {}", err))
+    }
+
+    pub fn try_update_node(
+        &mut self,
+        node_id: NodeId,
+        update_fn: &dyn Fn(&mut Node<T>),
+    ) -> Result<(), TreeError> {
+        let node = self
+            .get_node_mut(&node_id)
+            .ok_or(TreeError::MissingNode(node_id))?;
 
         update_fn(node);
+        Ok(())
     }
 
     pub fn is_empty(&self) -> bool {
-        self.nodes.is_empty()
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
     }
 
     pub fn get_node(&self, node_id: &NodeId) -> Option<&Node<T>> {
-        self.nodes.get(node_id)
+        let slot = self.slots.get(node_id.index as usize)?;
+        match slot.generation == node_id.generation {
+            true => slot.node.as_ref(),
+            false => None,
+        }
+    }
+
+    pub fn get_node_mut(&mut self, node_id: &NodeId) -> Option<&mut Node<T>> {
+        let slot = self.slots.get_mut(node_id.index as usize)?;
+        match slot.generation == node_id.generation {
+            true => slot.node.as_mut(),
+            false => None,
+        }
+    }
+}
+
+// traversals ------------------------------------------------------
+
+impl<T: Debug> Tree<T> {
+    /// The subtree of `start`, every node before its children. Nothing if
+    /// `start` doesn't exist.
+    pub fn pre_order(&self, start: NodeId) -> PreOrder<'_, T> {
+        PreOrder {
+            tree: self,
+            stack: vec![(start, 0)],
+        }
+    }
+
+    /// The subtree of `start`, every node after its children.
+    pub fn post_order(&self, start: NodeId) -> PostOrder<'_, T> {
+        PostOrder {
+            tree: self,
+            stack: vec![(start, 0, false)],
+        }
+    }
+
+    /// Computes a value for every node of the subtree of `start`, children
+    /// first, from the node's data and its children's values (in the order
+    /// of `children`), e.g. the total size of every directory.
+    pub fn fold_subtrees<R>(
+        &self,
+        start: NodeId,
+        mut f: impl FnMut(&T, &[&R]) -> R,
+    ) -> NodeValues<R> {
+        let mut results = NodeValues::with_slots(self.slots.len());
+        for (id, _, data) in self.post_order(start) {
+            let node = self.get_node(&id).unwrap();
+            let children: Vec<&R> = node.children.iter().map(|child| &results[child]).collect();
+            let result = f(data, &children);
+            results.insert(id, result);
+        }
+        results
+    }
+
+    /// The subtree of `start`, level by level.
+    pub fn level_order(&self, start: NodeId) -> LevelOrder<'_, T> {
+        LevelOrder {
+            tree: self,
+            queue: VecDeque::from([(start, 0)]),
+        }
+    }
+}
+
+/// Depth-first traversal yielding `(id, depth, data)`, the depth counted
+/// from the start node. Siblings come in the order of `children`.
+pub struct PreOrder<'a, T: Debug> {
+    tree: &'a Tree<T>,
+    stack: Vec<(NodeId, usize)>,
+}
+
+impl<'a, T: Debug> Iterator for PreOrder<'a, T> {
+    type Item = (NodeId, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, depth) = self.stack.pop()?;
+        let node = self.tree.get_node(&id)?;
+        self.stack
+            .extend(node.children.iter().rev().map(|child| (*child, depth + 1)));
+        Some((id, depth, &node.data))
+    }
+}
+
+/// Depth-first traversal yielding children before their parent.
+pub struct PostOrder<'a, T: Debug> {
+    tree: &'a Tree<T>,
+    /// nodes to visit, and whether their children were already pushed
+    stack: Vec<(NodeId, usize, bool)>,
+}
+
+impl<'a, T: Debug> Iterator for PostOrder<'a, T> {
+    type Item = (NodeId, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (id, depth, expanded) = self.stack.pop()?;
+            let node = self.tree.get_node(&id)?;
+            if expanded {
+                return Some((id, depth, &node.data));
+            }
+
+            self.stack.push((id, depth, true));
+            self.stack.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .map(|child| (*child, depth + 1, false)),
+            );
+        }
+    }
+}
+
+/// Breadth-first traversal.
+pub struct LevelOrder<'a, T: Debug> {
+    tree: &'a Tree<T>,
+    queue: VecDeque<(NodeId, usize)>,
+}
+
+impl<'a, T: Debug> Iterator for LevelOrder<'a, T> {
+    type Item = (NodeId, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, depth) = self.queue.pop_front()?;
+        let node = self.tree.get_node(&id)?;
+        self.queue
+            .extend(node.children.iter().map(|child| (*child, depth + 1)));
+        Some((id, depth, &node.data))
     }
 }
 
@@ -97,32 +393,91 @@ impl<'a, T: Debug> Iterator for TreeIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let idx = self.idx;
-        self.idx += 1;
-        let node_id = self.node_ids.get(idx)?;
-
-        self.tree.get_node(node_id).map(|node| &node.data)
+        self.slots
+            .find_map(|slot| slot.node.as_ref())
+            .map(|node| &node.data)
     }
 }
 
+/// Every node's data, in the order of the slots.
 pub struct TreeIter<'a, T: Debug> {
-    tree: &'a Tree<T>,
-    idx: usize,
-    node_ids: Vec<NodeId>,
+    slots: std::slice::Iter<'a, Slot<T>>,
 }
 
 impl<'a, T: Debug> From<&'a Tree<T>> for TreeIter<'a, T> {
     fn from(t: &'a Tree<T>) -> Self {
-        let ids = t.nodes.keys().map(|id| id.to_owned()).collect();
         Self {
-            tree: t,
-            idx: 0,
-            node_ids: ids,
+            slots: t.slots.iter(),
+        }
+    }
+}
+
+/// Index of a node in its [`Tree`]'s arena, with the generation of the slot
+/// it was created in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: u32,
+    generation: u32,
+}
+
+impl Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
+
+/// Values computed for some of a [`Tree`]'s nodes, stored by arena slot
+/// like the nodes themselves.
+#[derive(Debug, Clone)]
+pub struct NodeValues<R> {
+    slots: Vec<Option<(NodeId, R)>>,
+    len: usize,
+}
+
+impl<R> NodeValues<R> {
+    fn with_slots(slots: usize) -> Self {
+        Self {
+            slots: (0..slots).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, node_id: NodeId, value: R) {
+        let slot = &mut self.slots[node_id.index as usize];
+        if slot.replace((node_id, value)).is_none() {
+            self.len += 1;
         }
     }
+
+    pub fn get(&self, node_id: &NodeId) -> Option<&R> {
+        match self.slots.get(node_id.index as usize)? {
+            Some((id, value)) if id == node_id => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Every value, in the order of the slots.
+    pub fn values(&self) -> impl Iterator<Item = &R> {
+        self.slots.iter().flatten().map(|(_, value)| value)
+    }
 }
 
-pub type NodeId = Uuid;
+impl<R> Index<&NodeId> for NodeValues<R> {
+    type Output = R;
+
+    fn index(&self, node_id: &NodeId) -> &R {
+        self.get(node_id)
+            .unwrap_or_else(|| panic!("no value for node {}", node_id))
+    }
+}
 
 #[derive(Debug)]
 pub struct Node<T: Debug> {
@@ -133,7 +488,7 @@ pub struct Node<T: Debug> {
 
 #[cfg(test)]
 mod tests {
-    use super::Tree;
+    use super::{NodeId, Tree, TreeError};
 
     #[test]
     fn test_creating_and_deleting_nodes() {
@@ -163,7 +518,7 @@ mod tests {
             "Children of removed node should have parent set to None",
         );
 
-        vec![id2, id3, id4, id5].iter().for_each(|id| {
+        [id2, id3, id4, id5].iter().for_each(|id| {
             t.remove_node(*id);
         });
 
@@ -172,4 +527,206 @@ mod tests {
             "Tree should be empty after all nodes were removed"
         );
     }
+
+    #[test]
+    fn test_removing_subtrees() {
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let two = t.add_node(Some(root), 2);
+        t.add_node(Some(two), 3);
+        t.add_node(Some(two), 4);
+        let five = t.add_node(Some(root), 5);
+
+        assert_eq!(t.remove_subtree(two), vec![2, 3, 4]);
+        assert_eq!(t.len(), 2);
+        assert!(t.get_node(&two).is_none());
+        assert_eq!(t.get_node(&root).unwrap().children, vec![five]);
+        assert!(t.remove_subtree(two).is_empty());
+
+        assert_eq!(t.remove_subtree(root), vec![1, 5]);
+        assert!(t.is_empty());
+    }
+
+    #[test]
+    fn test_moving_and_detaching_nodes() {
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let two = t.add_node(Some(root), 2);
+        let three = t.add_node(Some(two), 3);
+        let four = t.add_node(Some(root), 4);
+
+        t.move_node(two, four);
+        assert_eq!(t.get_node(&root).unwrap().children, vec![four]);
+        assert_eq!(t.get_node(&four).unwrap().children, vec![two]);
+        assert_eq!(t.get_node(&two).unwrap().parent, Some(four));
+        let depths: Vec<(i32, usize)> = t
+            .pre_order(root)
+            .map(|(_, depth, data)| (*data, depth))
+            .collect();
+        assert_eq!(depths, [(1, 0), (4, 1), (2, 2), (3, 3)]);
+
+        t.detach(two);
+        assert!(t.get_node(&two).unwrap().parent.is_none());
+        assert!(t.get_node(&four).unwrap().children.is_empty());
+        assert_eq!(t.pre_order(two).count(), 2);
+        assert_eq!(t.get_node(&three).unwrap().parent, Some(two));
+        assert_eq!(t.len(), 4);
+    }
+
+    #[test]
+    fn test_tree_errors() {
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let child = t.add_node(Some(root), 2);
+        let removed = t.add_node(None, 3);
+        t.remove_node(removed);
+
+        assert_eq!(
+            t.try_add_node(Some(removed), 4),
+            Err(TreeError::MissingParent(removed))
+        );
+        assert_eq!(
+            t.try_update_node(removed, &|node| node.data += 1),
+            Err(TreeError::MissingNode(removed))
+        );
+        assert_eq!(
+            t.try_move_node(removed, root),
+            Err(TreeError::MissingNode(removed))
+        );
+        assert_eq!(
+            t.try_move_node(child, removed),
+            Err(TreeError::MissingParent(removed))
+        );
+        assert_eq!(
+            t.try_move_node(root, child),
+            Err(TreeError::WouldCreateCycle {
+                node: root,
+                new_parent: child
+            })
+        );
+        assert_eq!(
+            t.try_move_node(root, root),
+            Err(TreeError::WouldCreateCycle {
+                node: root,
+                new_parent: root
+            })
+        );
+        assert_eq!(t.try_detach(removed), Err(TreeError::MissingNode(removed)));
+        assert_eq!(
+            t.try_remove_node(removed),
+            Err(TreeError::MissingNode(removed))
+        );
+        assert_eq!(t.len(), 2);
+
+        assert_eq!(t.try_update_node(child, &|node| node.data += 1), Ok(()));
+        assert_eq!(t.try_remove_node(child), Ok(3));
+        assert_eq!(t.try_remove_subtree(root), Ok(vec![1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error while updating a node, node doesn't exist")]
+    fn test_updating_a_missing_node() {
+        let mut t = Tree::default();
+        let id = t.add_node(None, 1);
+        t.remove_node(id);
+        t.update_node(id, &|node| node.data += 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "is in the subtree of")]
+    fn test_moving_a_node_below_itself() {
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let child = t.add_node(Some(root), 2);
+        t.move_node(root, child);
+    }
+
+    #[test]
+    fn test_reusing_slots() {
+        let mut t = Tree::default();
+
+        let root = t.add_node(None, 1);
+        let child = t.add_node(Some(root), 2);
+        t.remove_node(child);
+        assert!(t.get_node(&child).is_none());
+
+        // the new node takes the removed one's slot, but not its id
+        let new_child = t.add_node(Some(root), 3);
+        assert_ne!(new_child, child);
+        assert!(t.get_node(&child).is_none());
+        assert_eq!(t.get_node(&new_child).unwrap().data, 3);
+        assert_eq!(t.get_node(&root).unwrap().children, vec![new_child]);
+
+        t.remove_node(child);
+        assert_eq!(t.len(), 2);
+        assert_eq!(t.into_iter().collect::<Vec<_>>(), vec![&1, &3]);
+    }
+
+    #[test]
+    fn test_traversals() {
+        //       1
+        //     /   \
+        //    2     3
+        //   / \     \
+        //  4   5     6
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let two = t.add_node(Some(root), 2);
+        let three = t.add_node(Some(root), 3);
+        t.add_node(Some(two), 4);
+        t.add_node(Some(two), 5);
+        t.add_node(Some(three), 6);
+
+        let walk = |order: &mut dyn Iterator<Item = (NodeId, usize, &i32)>| {
+            order
+                .map(|(_, depth, data)| (*data, depth))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            walk(&mut t.pre_order(root)),
+            [(1, 0), (2, 1), (4, 2), (5, 2), (3, 1), (6, 2)]
+        );
+        assert_eq!(
+            walk(&mut t.post_order(root)),
+            [(4, 2), (5, 2), (2, 1), (6, 2), (3, 1), (1, 0)]
+        );
+        assert_eq!(
+            walk(&mut t.level_order(root)),
+            [(1, 0), (2, 1), (3, 1), (4, 2), (5, 2), (6, 2)]
+        );
+
+        // from a subtree, depths count from its root
+        assert_eq!(walk(&mut t.pre_order(two)), [(2, 0), (4, 1), (5, 1)]);
+        let (id, _, _) = t.level_order(root).nth(2).unwrap();
+        assert_eq!(id, three);
+
+        t.remove_node(three);
+        assert_eq!(t.pre_order(three).count(), 0);
+    }
+
+    #[test]
+    fn test_fold_subtrees() {
+        let mut t = Tree::default();
+        let root = t.add_node(None, 1);
+        let two = t.add_node(Some(root), 2);
+        let three = t.add_node(Some(two), 3);
+        let four = t.add_node(Some(root), 4);
+
+        let sums = t.fold_subtrees(root, |data, children| {
+            data + children.iter().copied().sum::<i32>()
+        });
+        assert_eq!(sums.len(), 4);
+        assert_eq!(sums[&root], 10);
+        assert_eq!(sums[&two], 5);
+        assert_eq!(sums[&three], 3);
+        assert_eq!(sums[&four], 4);
+
+        let heights = t.fold_subtrees(two, |_, children| {
+            children.iter().map(|height| *height + 1).max().unwrap_or(0)
+        });
+        assert_eq!(heights.len(), 2);
+        assert_eq!(heights[&two], 1);
+        assert_eq!(heights.get(&root), None);
+        assert_eq!(heights.values().sum::<i32>(), 1);
+    }
 }
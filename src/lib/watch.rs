@@ -0,0 +1,252 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use crate::{
+    cli::Part,
+    io_utils::{default_input_dir, resolve_day_file, InputError},
+};
+
+/// Directories whose changes should rerun a day: its sources and inputs and
+/// the shared library.
+pub fn watched_dirs(day: u32) -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut dirs = vec![
+        root.join("src/bin").join(format!("day{}", day)),
+        root.join("src/lib"),
+    ];
+
+    let input_dir = default_input_dir().join(format!("day{}", day));
+    if !dirs.contains(&input_dir) {
+        dirs.push(input_dir);
+    }
+    dirs
+}
+
+/// Modification times of every file below some directories.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Missing directories are skipped, they may be created later.
+    pub fn take(dirs: &[PathBuf]) -> io::Result<Self> {
+        let mut snapshot = Self::default();
+        for dir in dirs {
+            snapshot.add_dir(dir)?;
+        }
+        Ok(snapshot)
+    }
+
+    fn add_dir(&mut self, dir: &Path) -> io::Result<()> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        for entry in entries {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                self.add_dir(&entry.path())?;
+            } else {
+                self.files.insert(entry.path(), metadata.modified()?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Files that were added, removed or modified since `earlier`.
+    pub fn changed_since(&self, earlier: &Snapshot) -> Vec<PathBuf> {
+        let modified = self
+            .files
+            .iter()
+            .filter(|(path, modified)| earlier.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone());
+        let removed = earlier
+            .files
+            .keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .cloned();
+
+        modified.chain(removed).collect()
+    }
+}
+
+/// Answers of one run, keyed by input name (`input`, `example1`, ...) and
+/// part. Failed parts hold the error message.
+pub type RunAnswers = BTreeMap<(String, Part), Result<String, String>>;
+
+/// The day's real input followed by its examples, as `(name, path)`.
+pub fn day_inputs(day: u32) -> Vec<(String, PathBuf)> {
+    let mut inputs = vec![];
+    if let Ok(path) = resolve_day_file(day, "input.in") {
+        inputs.push(("input".to_string(), path));
+    }
+
+    for n in 1.. {
+        match resolve_day_file(day, &format!("example{}.in", n)) {
+            Ok(path) => inputs.push((format!("example{}", n), path)),
+            Err(InputError::Unresolved { .. }) => break,
+            Err(_) => continue,
+        }
+    }
+    inputs
+}
+
+/// Builds and runs the day's binary on every input with `--format json`, so
+/// that the latest sources are used.
+pub fn run_day_binary(day: u32) -> RunAnswers {
+    let mut answers = RunAnswers::new();
+
+    for (name, path) in day_inputs(day) {
+        let output = Command::new(env!("CARGO"))
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .args(["run", "--quiet", "--bin", &format!("day{}", day), "--"])
+            .args(["--format", "json"])
+            .arg(&path)
+            .output();
+
+        let output = match output {
+            Ok(output) => output,
+            Err(err) => {
+                let message = format!("could not run cargo: {}", err);
+                for part in Part::ALL {
+                    answers.insert((name.clone(), part), Err(message.clone()));
+                }
+                continue;
+            }
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for part in Part::ALL {
+            let answer = stdout
+                .lines()
+                .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+                .find(|line| line["part"] == part.number())
+                .map(|line| match &line["answer"] {
+                    serde_json::Value::String(answer) => answer.clone(),
+                    answer => answer.to_string(),
+                })
+                .ok_or_else(|| {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    let message = stderr
+                        .lines()
+                        .rev()
+                        .find(|line| line.contains("panicked") || line.starts_with("error"))
+                        .unwrap_or("no answer");
+                    message.trim().to_string()
+                });
+            answers.insert((name.clone(), part), answer);
+        }
+    }
+
+    answers
+}
+
+/// How an answer differs from the previous run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub input: String,
+    pub part: Part,
+    pub before: Option<Result<String, String>>,
+    pub after: Option<Result<String, String>>,
+}
+
+pub fn diff_answers(previous: &RunAnswers, current: &RunAnswers) -> Vec<Change> {
+    let mut keys: Vec<&(String, Part)> = previous.keys().chain(current.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| previous.get(*key) != current.get(*key))
+        .map(|key| Change {
+            input: key.0.clone(),
+            part: key.1,
+            before: previous.get(key).cloned(),
+            after: current.get(key).cloned(),
+        })
+        .collect()
+}
+
+fn describe(answer: &Option<Result<String, String>>) -> String {
+    match answer {
+        None => "-".to_string(),
+        Some(Ok(answer)) if answer.contains('\n') => format!("\n{}\n", answer),
+        Some(Ok(answer)) => answer.clone(),
+        Some(Err(message)) => format!("error ({})", message),
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} -> {}",
+            self.input,
+            self.part,
+            describe(&self.before),
+            describe(&self.after)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, thread, time::Duration};
+
+    use super::{diff_answers, RunAnswers, Snapshot};
+    use crate::cli::Part;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.rs"), "a").unwrap();
+        fs::write(dir.join("nested/b.rs"), "b").unwrap();
+
+        let dirs = [dir.clone(), dir.join("missing")];
+        let before = Snapshot::take(&dirs).unwrap();
+        assert!(Snapshot::take(&dirs)
+            .unwrap()
+            .changed_since(&before)
+            .is_empty());
+
+        thread::sleep(Duration::from_millis(20));
+        fs::write(dir.join("nested/b.rs"), "bb").unwrap();
+        fs::remove_file(dir.join("a.rs")).unwrap();
+        let after = Snapshot::take(&dirs).unwrap();
+        let changed = after.changed_since(&before);
+        assert_eq!(changed, vec![dir.join("nested/b.rs"), dir.join("a.rs")]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let answers = |entries: &[(&str, Part, &str)]| -> RunAnswers {
+            entries
+                .iter()
+                .map(|(input, part, answer)| ((input.to_string(), *part), Ok(answer.to_string())))
+                .collect()
+        };
+        let previous = answers(&[("input", Part::One, "1"), ("example1", Part::One, "2")]);
+        let current = answers(&[("input", Part::One, "1"), ("example1", Part::One, "3")]);
+        assert!(diff_answers(&previous, &previous).is_empty());
+
+        let changes = diff_answers(&previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].to_string(), "example1 Part One: 2 -> 3");
+
+        let changes = diff_answers(&RunAnswers::new(), &current);
+        assert_eq!(changes[0].to_string(), "example1 Part One: - -> 3");
+    }
+}